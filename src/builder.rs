@@ -0,0 +1,120 @@
+
+use {VecList,};
+use std::num::NonZeroUsize;
+
+/// How the buffer of a [`VecList`] grows when it runs out of space.
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub enum GrowthPolicy {
+  /// Double the capacity of the buffer.
+  Double,
+  /// Grow the buffer by exactly as much as is needed.
+  Exact,
+  /// Grow the buffer by at least a fixed number of [`Node`]s.
+  Linear(NonZeroUsize),
+}
+
+impl Default for GrowthPolicy {
+  #[inline]
+  fn default() -> Self { GrowthPolicy::Double }
+}
+
+/// When a [`VecList`] compacts itself after values are removed.
+/// 
+/// Compacting moves values to other slots so handles into a [`VecList`] which compacts
+/// automatically should not be relied upon.
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub enum CompactionPolicy {
+  /// Never compact automatically.
+  Never,
+  /// Compact once more than the given percentage of the capacity is free slots.
+  FreePercent(u8),
+}
+
+impl Default for CompactionPolicy {
+  #[inline]
+  fn default() -> Self { CompactionPolicy::Never }
+}
+
+/// A builder which bundles the tuning parameters of a [`VecList`].
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug,)]
+pub struct VecListBuilder {
+  /// The initial capacity of the buffer.
+  capacity: usize,
+  /// The growth policy of the buffer.
+  growth: GrowthPolicy,
+  /// The compaction policy of the buffer.
+  compaction: CompactionPolicy,
+}
+
+impl VecListBuilder {
+  /// Constructs a new [`VecListBuilder`] with no initial capacity and the default
+  /// policies.
+  #[inline]
+  pub fn new() -> Self { Self::default() }
+  /// Sets the initial capacity of the buffer.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of [`Node`]s to allocate space for.  
+  #[inline]
+  pub fn capacity(mut self, capacity: usize,) -> Self {
+    self.capacity = capacity; self
+  }
+  /// Sets the growth policy of the buffer.
+  /// 
+  /// # Params
+  /// 
+  /// growth --- The growth policy to use.  
+  #[inline]
+  pub fn growth(mut self, growth: GrowthPolicy,) -> Self {
+    self.growth = growth; self
+  }
+  /// Sets the compaction policy of the buffer.
+  /// 
+  /// # Params
+  /// 
+  /// compaction --- The compaction policy to use.  
+  #[inline]
+  pub fn compaction(mut self, compaction: CompactionPolicy,) -> Self {
+    self.compaction = compaction; self
+  }
+  /// Constructs a new empty [`VecList`] using the tuning parameters.
+  #[inline]
+  pub fn build<T,>(self,) -> VecList<T,> {
+    let mut list = VecList::with_capacity_and_policy(self.capacity, self.growth,);
+
+    list.set_compaction_policy(self.compaction,); list
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_builder() {
+    let mut list = VecListBuilder::new()
+      .capacity(2,)
+      .growth(GrowthPolicy::Linear(NonZeroUsize::new(5,).unwrap()),)
+      .build::<i32>();
+
+    assert_eq!(list.capacity(), 2, "`VecListBuilder::build` created with incorrect capacity",);
+
+    for i in 0..3 { list.push_back(i,); }
+    assert_eq!(list.capacity(), 7, "`GrowthPolicy::Linear` grew by the wrong amount",);
+
+    let mut list = VecListBuilder::new()
+      .capacity(4,)
+      .compaction(CompactionPolicy::FreePercent(25,),)
+      .build::<i32>();
+
+    list.extend(0..4,);
+    list.pop_front();
+    assert_eq!(list.free_slots(), 1, "`CompactionPolicy::FreePercent` compacted too early",);
+    list.pop_front();
+    assert_eq!(list.free_slots(), 0, "`CompactionPolicy::FreePercent` did not compact",);
+    assert_eq!(list.dump_layout().head, Some(0), "`CompactionPolicy::FreePercent` did not move the values",);
+    assert_eq!(list.to_vec(), vec![2, 3,], "`CompactionPolicy::FreePercent` changed the values",);
+    assert_eq!(list.capacity(), 4, "`CompactionPolicy::FreePercent` changed the capacity",);
+  }
+}
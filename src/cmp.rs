@@ -0,0 +1,133 @@
+
+use {VecList, Alloc,};
+use std::{cmp::Ordering, hash::{Hash, Hasher,}, collections::{LinkedList, VecDeque,},};
+
+impl<T: PartialEq, A: Alloc + Clone,> PartialEq for VecList<T, A,> {
+  /// Compares the values of the [`VecList`]s in list order.
+  #[inline]
+  fn eq(&self, other: &Self,) -> bool {
+    self.len() == other.len() && self.iter().eq(other,)
+  }
+}
+
+impl<T: Eq, A: Alloc + Clone,> Eq for VecList<T, A,> {}
+
+impl<T: PartialEq<U>, U, A: Alloc + Clone,> PartialEq<[U]> for VecList<T, A,> {
+  /// Compares the values of the [`VecList`] in list order with the slice.
+  #[inline]
+  fn eq(&self, other: &[U],) -> bool {
+    self.len() == other.len() && self.iter().zip(other,).all(|(a, b,)| a == b,)
+  }
+}
+
+impl<'a, T: PartialEq<U>, U, A: Alloc + Clone,> PartialEq<&'a [U]> for VecList<T, A,> {
+  #[inline]
+  fn eq(&self, other: &&'a [U],) -> bool { *self == **other }
+}
+
+impl<T: PartialEq<U>, U, A: Alloc + Clone,> PartialEq<Vec<U>> for VecList<T, A,> {
+  #[inline]
+  fn eq(&self, other: &Vec<U>,) -> bool { *self == **other }
+}
+
+impl<T: PartialEq<U>, U, A: Alloc + Clone,> PartialEq<VecDeque<U>> for VecList<T, A,> {
+  /// Compares the values of the [`VecList`] in list order with the [`VecDeque`].
+  #[inline]
+  fn eq(&self, other: &VecDeque<U>,) -> bool {
+    self.len() == other.len() && self.iter().zip(other,).all(|(a, b,)| a == b,)
+  }
+}
+
+impl<T: PartialEq<U>, U, A: Alloc + Clone,> PartialEq<LinkedList<U>> for VecList<T, A,> {
+  /// Compares the values of the [`VecList`] in list order with the [`LinkedList`].
+  #[inline]
+  fn eq(&self, other: &LinkedList<U>,) -> bool {
+    self.len() == other.len() && self.iter().zip(other,).all(|(a, b,)| a == b,)
+  }
+}
+
+//Arrays are compared for every length the standard library implements traits for.
+macro_rules! array_eq {
+  ($($len:expr)*) => {
+    $(impl<T: PartialEq<U>, U, A: Alloc + Clone,> PartialEq<[U; $len]> for VecList<T, A,> {
+      #[inline]
+      fn eq(&self, other: &[U; $len],) -> bool { *self == other[..] }
+    })*
+  };
+}
+
+array_eq! {
+  0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+  17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+impl<T: PartialOrd, A: Alloc + Clone,> PartialOrd for VecList<T, A,> {
+  /// Lexicographically compares the values of the [`VecList`]s in list order.
+  #[inline]
+  fn partial_cmp(&self, other: &Self,) -> Option<Ordering> {
+    self.iter().partial_cmp(other,)
+  }
+}
+
+impl<T: Ord, A: Alloc + Clone,> Ord for VecList<T, A,> {
+  /// Lexicographically compares the values of the [`VecList`]s in list order.
+  #[inline]
+  fn cmp(&self, other: &Self,) -> Ordering { self.iter().cmp(other,) }
+}
+
+impl<T: Hash, A: Alloc + Clone,> Hash for VecList<T, A,> {
+  /// Hashes the length and then the values of the [`VecList`] in list order.
+  fn hash<H: Hasher,>(&self, state: &mut H,) {
+    self.len().hash(state,);
+    for value in self { value.hash(state,) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::hash_map::DefaultHasher;
+
+  #[test]
+  fn test_cmp() {
+    let hash = |list: &VecList<i32,>,| {
+      let mut state = DefaultHasher::new();
+
+      list.hash(&mut state,); state.finish()
+    };
+    let list = vec![1, 2, 3,].into_iter().collect::<VecList<i32,>>();
+    let mut other = VecList::new();
+
+    //Build the same values in a different buffer order.
+    other.push_back(3,);
+    other.push_front(2,);
+    other.push_front(1,);
+
+    assert_eq!(list, other, "`VecList::eq` compared buffer order",);
+    assert_eq!(hash(&list,), hash(&other,), "`VecList::hash` hashed buffer order",);
+
+    other.pop_back();
+    assert!(other < list, "`VecList::cmp` incorrect ordering",);
+    other.push_back(4,);
+    assert_eq!(list.cmp(&other,), Ordering::Less, "`VecList::cmp` incorrect ordering",);
+  }
+  #[test]
+  fn test_eq_slice() {
+    let list = vec![1, 2, 3,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list, [1, 2, 3,], "`VecList` not equal to an array",);
+    assert_eq!(list, vec![1, 2, 3,], "`VecList` not equal to a `Vec`",);
+    assert_eq!(list, &[1, 2, 3,][..], "`VecList` not equal to a slice",);
+    assert_ne!(list, [1, 2,], "`VecList` equal to a shorter array",);
+    assert_ne!(list, vec![1, 2, 4,], "`VecList` equal to a different `Vec`",);
+  }
+  #[test]
+  fn test_eq_collections() {
+    let list = vec![1, 2, 3,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list, (1..4).collect::<VecDeque<i32,>>(), "`VecList` not equal to a `VecDeque`",);
+    assert_ne!(list, (0..3).collect::<VecDeque<i32,>>(), "`VecList` equal to a different `VecDeque`",);
+    assert_eq!(list, (1..4).collect::<LinkedList<i32,>>(), "`VecList` not equal to a `LinkedList`",);
+    assert_ne!(list, (1..3).collect::<LinkedList<i32,>>(), "`VecList` equal to a shorter `LinkedList`",);
+  }
+}
@@ -0,0 +1,70 @@
+
+use {VecList, nodes::Node,};
+use serde::{
+  Serialize, Serializer, Deserialize, Deserializer,
+  ser::SerializeSeq,
+  de::{Visitor, SeqAccess,},
+};
+use std::{fmt, mem, marker::PhantomData,};
+
+impl<T: Serialize,> Serialize for VecList<T,> {
+  /// Writes the values as a sequence in list order; the same format as a [`Vec`] so
+  /// binary formats such as `bincode` write the same bytes.
+  fn serialize<S: Serializer,>(&self, serializer: S,) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(self.len()),)?;
+
+    for value in self { seq.serialize_element(value,)? }
+
+    seq.end()
+  }
+}
+
+/// Reads a sequence of values into a [`VecList`].
+struct ListVisitor<T,>(PhantomData<T>,);
+
+impl<'de, T: Deserialize<'de>,> Visitor<'de> for ListVisitor<T,> {
+  type Value = VecList<T,>;
+
+  fn expecting(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt.write_str("a sequence",) }
+  fn visit_seq<S: SeqAccess<'de>,>(self, mut seq: S,) -> Result<Self::Value, S::Error> {
+    //Do not trust the length for more than a few pages of `Node`s up front.
+    let capacity = usize::min(seq.size_hint().unwrap_or(0,), 4096 / usize::max(mem::size_of::<Node<T,>>(), 1,),);
+    let mut list = VecList::with_capacity(capacity,);
+
+    while let Some(value) = seq.next_element()? { list.push_back(value,); }
+
+    Ok(list)
+  }
+}
+
+impl<'de, T: Deserialize<'de>,> Deserialize<'de> for VecList<T,> {
+  /// Reads a [`VecList`] written in the same format as a [`Vec`] with its [`Node`]s
+  /// laid out contiguously.
+  #[inline]
+  fn deserialize<D: Deserializer<'de>,>(deserializer: D,) -> Result<Self, D::Error> {
+    deserializer.deserialize_seq(ListVisitor(PhantomData,),)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_codec() {
+    let mut list = VecList::new();
+
+    list.push_back(2u16,);
+    list.push_front(1,);
+    list.push_back(3,);
+
+    let bytes = ::bincode::serialize(&list,).expect("`VecList::serialize` failed");
+
+    assert_eq!(bytes, ::bincode::serialize(&vec![1u16, 2, 3,],).expect("`Vec::serialize` failed"),
+      "`VecList::serialize` incorrect wire format",);
+
+    let list = ::bincode::deserialize::<VecList<u16,>>(&bytes,).expect("`VecList::deserialize` failed");
+
+    assert_eq!(list.to_vec(), vec![1, 2, 3,], "`VecList::deserialize` incorrect values",);
+  }
+}
@@ -0,0 +1,359 @@
+
+use {VecList, NodeId, NonZeroUsize, CompactionPolicy, Alloc,};
+use raw_vec::RawVec;
+use std::{ptr, mem,};
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Rewrites the [`Node`]s so they are laid out contiguously in list order at the start
+  /// of the buffer, restoring sequential access after heavy insert and remove churn.
+  /// 
+  /// Handles to values which move are invalidated, see [`VecList::compact_with`] to
+  /// track them.
+  #[inline]
+  pub fn compact(&mut self,) { self.compact_with(|_, _,| (),) }
+  /// Rewrites the [`Node`]s so they are laid out contiguously in list order at the start
+  /// of the buffer, restoring sequential access after heavy insert and remove churn.
+  /// 
+  /// `remap` is called with the old and new handle of every value which is moved to a
+  /// different slot so external structures holding [`NodeId`]s can be updated. It is
+  /// only called once the [`VecList`] is consistent again. Handles to values which do
+  /// not move stay valid.
+  /// 
+  /// # Params
+  /// 
+  /// remap --- Called with the old and new handle of each moved value.  
+  #[inline]
+  pub fn compact_with<F,>(&mut self, remap: F,)
+    where F: FnMut(NodeId, NodeId,), {
+    let (cap, node_count,) = (self.capacity(), self.node_count,);
+
+    self.relocate(cap, node_count, remap,)
+  }
+  /// Shrinks the buffer to exactly the number of values in the [`VecList`].
+  /// 
+  /// The values are first moved into list order at the start of the buffer, as
+  /// [`VecList::compact_with`] does, so empty slots in the middle of the buffer are
+  /// reclaimed as well. Handles to moved values are invalidated; slots past the end of
+  /// the new buffer are forgotten but new slots start past their generations so stale
+  /// handles to them stay invalid.
+  #[inline]
+  pub fn shrink_to_fit(&mut self,) {
+    let len = self.len();
+
+    self.relocate(len, len, |_, _,| (),)
+  }
+  /// Compacts the [`VecList`] and calls `f` with the values as a mutable slice in list
+  /// order, letting slice algorithms run over the values.
+  /// 
+  /// The values are moved into a temporary buffer for the duration of `f` and moved back
+  /// afterwards, invalidating every handle into the [`VecList`]. If `f` panics the values
+  /// are moved back in whatever order `f` left them.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Called with the values in list order.  
+  pub(crate) fn with_contiguous<R, F,>(&mut self, f: F,) -> R
+    where F: FnOnce(&mut [T],) -> R, {
+    let len = match self.ends {
+      Some((len, _, _,)) => len,
+      None => return f(&mut [],),
+    };
+
+    //Lay the values out in list order in the first `len` slots.
+    self.compact_with(|_, _,| (),);
+    //Forget the values while they are outside the list; the guard moves them back even
+    //if `f` panics.
+    self.ends = None;
+
+    let mut guard = WriteBack { list: self, values: Vec::with_capacity(len.get(),), };
+
+    for slot in 0..len.get() {
+      let value = unsafe { ptr::read(guard.list.value(slot,),) };
+
+      guard.values.push(value,);
+    }
+
+    f(&mut guard.values,)
+  }
+  /// Returns the policy deciding when the [`VecList`] compacts itself.
+  #[inline]
+  pub fn compaction_policy(&self,) -> CompactionPolicy { self.compaction }
+  /// Sets the policy deciding when the [`VecList`] compacts itself.
+  /// 
+  /// The policy is consulted after values are removed by popping, removing by index,
+  /// handle or predicate, truncating, retaining, sampling or clearing, once a [`Drain`],
+  /// [`ExtractIf`], [`ExtractWhile`] or [`DrainDuplicates`] is dropped, which covers
+  /// splitting and deduplicating, and once a [`CursorMut`] is dropped. Compacting moves
+  /// the values into list order at the start of the buffer and forgets the empty slots,
+  /// keeping the capacity.
+  /// 
+  /// # Params
+  /// 
+  /// policy --- The compaction policy to use.  
+  #[inline]
+  pub fn set_compaction_policy(&mut self, policy: CompactionPolicy,) { self.compaction = policy }
+  /// Compacts the [`VecList`] if its compaction policy asks for it.
+  pub(crate) fn after_removal(&mut self,) {
+    if let CompactionPolicy::FreePercent(percent) = self.compaction {
+      let cap = self.capacity();
+
+      if self.free_slots().saturating_mul(100,) > cap.saturating_mul(percent as usize,) {
+        let len = self.len();
+
+        self.relocate(cap, len, |_, _,| (),)
+      }
+    }
+  }
+  /// Moves the values into list order at the start of a new buffer of `cap` slots.
+  /// 
+  /// Empty slots past `node_count` or which do not fit in the new buffer are forgotten;
+  /// slots allocated afterwards start past the generations of the forgotten slots.
+  /// 
+  /// # Params
+  /// 
+  /// cap --- The capacity of the new buffer; at least `self.len()`.  
+  /// node_count --- The number of slots to keep; at least `self.len()`.  
+  /// remap --- Called with the old and new handle of each moved value.  
+  fn relocate<F,>(&mut self, cap: usize, node_count: usize, mut remap: F,)
+    where F: FnMut(NodeId, NodeId,), {
+    let len = self.len();
+
+    //With no values to move and no slots to keep the buffer can be kept as it is.
+    if len == 0 && node_count == 0 && cap == self.buf.cap() {
+      for index in 0..self.node_count {
+        let generation = unsafe { (*self.node(index,)).generation | 1 }.wrapping_add(1,);
+
+        self.fresh_generation = usize::max(self.fresh_generation, generation,);
+      }
+
+      self.node_count = 0;
+      self.empty = None;
+
+      return
+    }
+
+    let buf = RawVec::with_capacity_in(cap, self.buf.alloc().clone(),);
+    let old = mem::replace(&mut self.buf, buf,);
+    //The number of slots which are still used.
+    let node_count = usize::min(usize::min(self.node_count, node_count,), self.buf.cap(),);
+    //The handles of the values which moved, by their new slot.
+    let mut moved = Vec::new();
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    unsafe {
+      //Move the values into list order.
+      for index in 0..len {
+        let node = &*old.ptr().add(ptr.expect("`VecList::compact_with` ran out of `Node`s"),);
+        let slot = old.ptr().add(index,);
+        let new = self.buf.ptr().add(index,);
+
+        //A value which moves takes a newer generation than the slot has had.
+        let generation = if ptr == Some(index) { node.generation }
+          else {
+            moved.push((index, NodeId::new(ptr.unwrap(), node.generation,),),);
+
+            ((*slot).generation | 1).wrapping_add(1,)
+          };
+
+        ptr::copy_nonoverlapping(&*node.value, &mut *(*new).value, 1,);
+        (*new).prev = index.checked_sub(1,);
+        (*new).next = if index + 1 < len { Some(index + 1) } else { None };
+        (*new).generation = generation;
+
+        ptr = node.next;
+      }
+      //Every other used slot which fits becomes empty.
+      for index in len..node_count {
+        let new = self.buf.ptr().add(index,);
+
+        (*new).prev = None;
+        (*new).next = if index + 1 < node_count { Some(index + 1) } else { None };
+        (*new).generation = (*old.ptr().add(index,)).generation | 1;
+      }
+      //New slots must not reuse the generations of the forgotten slots.
+      for index in node_count..self.node_count {
+        let generation = ((*old.ptr().add(index,)).generation | 1).wrapping_add(1,);
+
+        self.fresh_generation = usize::max(self.fresh_generation, generation,);
+      }
+    }
+
+    self.node_count = node_count;
+    self.ends = NonZeroUsize::new(len,).map(|len| (len, 0, len.get() - 1,));
+    self.empty = NonZeroUsize::new(node_count - len,).map(|empty| (empty, len,));
+
+    for (index, id,) in moved { remap(id, self.handle(index,),) }
+  }
+}
+
+/// Moves the values lent out by [`VecList::with_contiguous`] back into the first slots
+/// of the [`VecList`] when dropped.
+struct WriteBack<'t, T: 't, A: 't + Alloc + Clone,> {
+  /// The [`VecList`] the values were moved out of.
+  list: &'t mut VecList<T, A,>,
+  /// The values in the order they are written back.
+  values: Vec<T>,
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Drop for WriteBack<'t, T, A,> {
+  fn drop(&mut self,) {
+    let len = self.values.len();
+
+    for (slot, value,) in self.values.drain(..,).enumerate() {
+      unsafe {
+        let node = &mut *self.list.node_mut(slot,);
+
+        ptr::write(&mut *node.value, value,);
+        //The slot holds a different value now.
+        node.generation = node.generation.wrapping_add(2,);
+      }
+    }
+
+    self.list.ends = NonZeroUsize::new(len,).map(|len| (len, 0, len.get() - 1,));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_compact_with() {
+    let mut list = VecList::new();
+
+    for i in 0..4 { list.push_front(i,); }
+    list.pop_back();
+
+    let old = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let mut moves = Vec::new();
+
+    list.compact_with(|old, new,| moves.push((old, new,),),);
+
+    let layout = list.dump_layout();
+
+    assert_eq!(list.to_vec(), vec![3, 2, 1,], "`VecList::compact_with` changed the order",);
+    assert_eq!((layout.head, layout.tail, layout.free_head,), (Some(0), Some(2), Some(3),), "`VecList::compact_with` incorrect layout",);
+    assert_eq!(moves.len(), 3, "`VecList::compact_with` reported the wrong moves",);
+    assert!(!list.is_valid_handle(old,), "`VecList::compact_with` left a moved handle valid",);
+    assert!(moves.iter().all(|&(old, new,)| unsafe { list.value(new.slot(),) } == &[3, 2, 1,][new.slot()] && old != new,),
+      "`VecList::compact_with` reported an incorrect move",);
+
+    list.push_back(0,);
+    assert_eq!(list.to_vec(), vec![3, 2, 1, 0,], "`VecList::compact_with` corrupted the empty stack",);
+  }
+  #[test]
+  fn test_compact() {
+    let mut list = (0..4).collect::<VecList<i32,>>();
+
+    list.push_front(6,);
+    list.remove(3,);
+    list.pop_back();
+
+    let capacity = list.capacity();
+
+    list.compact();
+    assert_eq!(list.to_vec(), vec![6, 0, 1,], "`VecList::compact` changed the order",);
+    assert_eq!(list.dump_layout().head, Some(0), "`VecList::compact` did not move the front",);
+    assert!(list.is_contiguous(), "`VecList::compact` did not lay the values out in order",);
+    assert_eq!(list.capacity(), capacity, "`VecList::compact` changed the capacity",);
+  }
+  #[test]
+  fn test_shrink_to_fit() {
+    let mut list = (0..4).collect::<VecList<i32,>>();
+
+    list.remove(1,);
+    list.push_front(4,);
+    list.pop_back();
+    list.shrink_to_fit();
+    assert_eq!(list.to_vec(), vec![4, 0, 2,], "`VecList::shrink_to_fit` changed the values",);
+    assert_eq!(list.capacity(), 3, "`VecList::shrink_to_fit` did not shrink the buffer",);
+    assert_eq!(list.dump_layout().free_len, 0, "`VecList::shrink_to_fit` kept empty slots",);
+
+    list.push_back(5,);
+    assert_eq!(list.to_vec(), vec![4, 0, 2, 5,], "`VecList::shrink_to_fit` corrupted the buffer",);
+  }
+  #[test]
+  fn test_with_contiguous() {
+    use std::panic::{self, AssertUnwindSafe,};
+
+    let mut list = (0..4).collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+
+    list.pop_back();
+    list.push_front(3,);
+    assert_eq!(list.with_contiguous(|values,| { values.sort_unstable(); values.len() },), 4, "`VecList::with_contiguous` incorrect result",);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3,], "`VecList::with_contiguous` did not write the values back",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), vec![3, 2, 1, 0,], "`VecList::with_contiguous` broke the `prev` links",);
+    assert!(!list.is_valid_handle(front,), "`VecList::with_contiguous` left a handle valid",);
+
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let res = panic::catch_unwind(AssertUnwindSafe(|| list.with_contiguous(|values,| {
+      values.swap(0, 3,);
+      panic!("`VecList::with_contiguous` test panic")
+    },),),);
+
+    assert!(res.is_err(), "`VecList::with_contiguous` did not propagate the panic",);
+    assert_eq!(list.to_vec(), vec![3, 1, 2, 0,], "`VecList::with_contiguous` lost the values on panic",);
+    assert!(!list.is_valid_handle(front,), "`VecList::with_contiguous` left a handle valid on panic",);
+  }
+  #[test]
+  fn test_forgotten_slots() {
+    let mut list = (0..4).collect::<VecList<i32,>>();
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    list.pop_back();
+    list.shrink_to_fit();
+    assert_eq!(list.push_back(4,).slot(), back.slot(), "`VecList::push_back` did not reuse the forgotten slot",);
+    assert!(!list.is_valid_handle(back,), "`VecList::shrink_to_fit` revived a stale handle",);
+
+    list.set_compaction_policy(CompactionPolicy::FreePercent(25,),);
+
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    list.pop_back();
+    list.pop_back();
+    assert_eq!(list.free_slots(), 0, "`CompactionPolicy::FreePercent` did not compact",);
+    list.push_back(2,);
+    assert_eq!(list.push_back(3,).slot(), back.slot(), "`VecList::push_back` did not reuse the forgotten slot",);
+    assert!(!list.is_valid_handle(back,), "`CompactionPolicy::FreePercent` revived a stale handle",);
+    assert_eq!(list.get_by_handle(back,), None, "`VecList::get_by_handle` accepted a stale handle",);
+  }
+  #[test]
+  fn test_after_removal() {
+    let mut list = (0..8).collect::<VecList<i32,>>();
+
+    list.set_compaction_policy(CompactionPolicy::FreePercent(0,),);
+
+    list.drain(1..2,);
+    assert_eq!(list.free_slots(), 0, "`Drain` ignored the compaction policy",);
+    list.split_off(6,);
+    assert_eq!(list.free_slots(), 0, "`VecList::split_off` ignored the compaction policy",);
+    list.extract_if(.., |value,| *value == 2,).count();
+    assert_eq!(list.free_slots(), 0, "`ExtractIf` ignored the compaction policy",);
+    list.drain_filter_while(|value,| ::ControlFlow::Continue(*value == 0),).count();
+    assert_eq!(list.free_slots(), 0, "`ExtractWhile` ignored the compaction policy",);
+    list.push_front(3,);
+    list.dedup();
+    assert_eq!(list.free_slots(), 0, "`VecList::dedup` ignored the compaction policy",);
+    assert_eq!(list.to_vec(), vec![3, 4, 5, 6,], "`VecList::dedup` removed the wrong values",);
+
+    {
+      let mut cursor = list.cursor_front_mut();
+
+      cursor.move_next();
+      assert_eq!(cursor.remove_current(), Some(4), "`CursorMut::remove_current` removed the wrong value",);
+      assert_eq!(cursor.split_before().to_vec(), vec![3,], "`CursorMut::split_before` moved the wrong values",);
+      assert_eq!(cursor.current().cloned(), Some(5), "`CursorMut::split_before` lost the current value",);
+      assert_eq!(cursor.split_after().to_vec(), vec![6,], "`CursorMut::split_after` moved the wrong values",);
+      assert_eq!(cursor.current().cloned(), Some(5), "`CursorMut::split_after` lost the current value",);
+    }
+    assert_eq!(list.free_slots(), 0, "`CursorMut` ignored the compaction policy",);
+    assert_eq!(list.to_vec(), vec![5,], "`CursorMut` broke the `VecList`",);
+
+    let cap = list.capacity();
+
+    list.clear();
+    assert_eq!(list.free_slots(), 0, "`VecList::clear` ignored the compaction policy",);
+    assert_eq!(list.capacity(), cap, "`VecList::clear` changed the capacity",);
+  }
+}
@@ -0,0 +1,175 @@
+
+use {VecList, NodeId,};
+use std::sync::{Mutex, MutexGuard, atomic::{AtomicUsize, Ordering,},};
+
+/// A handle to a value inside a [`ConcurrentVecList`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug,)]
+pub struct ShardedNodeId {
+  /// The index of the shard holding the value.
+  shard: usize,
+  /// The handle to the value inside its shard.
+  id: NodeId,
+}
+
+/// A thread safe queue built from several [`VecList`]s which are locked independently.
+/// 
+/// Values are spread over the shards in the order they are pushed so concurrent pushes,
+/// pops and handle based removals only contend when they touch the same shard. Every
+/// value is tagged with its push order so values are popped in the order they were
+/// pushed.
+pub struct ConcurrentVecList<T,> {
+  /// The shards of the queue; each value is tagged with its push order.
+  shards: Vec<Mutex<VecList<(usize, T,),>>,>,
+  /// The push order of the front value of each shard or `EMPTY`; only written while
+  /// the shard is locked.
+  heads: Vec<AtomicUsize>,
+  /// The push order of the next value.
+  next: AtomicUsize,
+}
+
+/// The head order of an empty shard.
+const EMPTY: usize = usize::max_value();
+
+impl<T,> ConcurrentVecList<T,> {
+  /// Constructs a new empty [`ConcurrentVecList`] with `shards` independently locked
+  /// shards.
+  /// 
+  /// # Params
+  /// 
+  /// shards --- The number of shards.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `shards` is `0`.
+  pub fn new(shards: usize,) -> Self {
+    assert!(shards > 0, "`ConcurrentVecList::new` must have at least one shard",);
+
+    Self {
+      shards: (0..shards).map(|_| Mutex::new(VecList::new(),),).collect(),
+      heads: (0..shards).map(|_| AtomicUsize::new(EMPTY,),).collect(),
+      next: AtomicUsize::new(0,),
+    }
+  }
+  /// Locks the shard at `shard`.
+  /// 
+  /// # Params
+  /// 
+  /// shard --- The index of the shard.  
+  #[inline]
+  fn lock<'t,>(&'t self, shard: usize,) -> MutexGuard<'t, VecList<(usize, T,),>,> {
+    self.shards[shard].lock().expect("`ConcurrentVecList` shard poisoned")
+  }
+  /// Records the push order of the front value of the locked shard at `shard`.
+  /// 
+  /// # Params
+  /// 
+  /// shard --- The index of the shard.  
+  /// list --- The locked shard.  
+  #[inline]
+  fn update_head(&self, shard: usize, list: &VecList<(usize, T,),>,) {
+    self.heads[shard].store(list.front().map_or(EMPTY, |value,| value.0,), Ordering::Release,)
+  }
+  /// Returns the number of values in the [`ConcurrentVecList`].
+  /// 
+  /// Other threads may change the length as soon as this returns.
+  pub fn len(&self,) -> usize {
+    (0..self.shards.len()).map(|shard| self.lock(shard,).len(),).sum()
+  }
+  /// Pushes `value` onto the back of the [`ConcurrentVecList`] and returns a handle to it.
+  /// 
+  /// Only the shard receiving `value` is locked.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to push.  
+  pub fn push_back(&self, value: T,) -> ShardedNodeId {
+    let order = self.next.fetch_add(1, Ordering::Relaxed,);
+    let shard = order % self.shards.len();
+    let mut list = self.lock(shard,);
+
+    //Keep each shard sorted by push order.
+    let id = list.insert_sorted_by((order, value,), |a, b,| a.0.cmp(&b.0,),);
+
+    self.update_head(shard, &list,);
+    ShardedNodeId { shard, id, }
+  }
+  /// Pops the oldest value off the front of the [`ConcurrentVecList`].
+  /// 
+  /// The oldest value is found from the recorded front of each shard without locking
+  /// them and only the shard holding it is locked. If another thread changed that
+  /// shard first the search is retried.
+  pub fn pop_front(&self,) -> Option<T> {
+    loop {
+      let (order, shard,) = self.heads.iter().enumerate()
+        .map(|(shard, head,)| (head.load(Ordering::Acquire,), shard,),)
+        .min()?;
+
+      if order == EMPTY { return None }
+
+      let mut list = self.lock(shard,);
+
+      if list.front().map(|value,| value.0,) != Some(order) { continue }
+
+      let value = list.pop_front().map(|(_, value,)| value,);
+
+      self.update_head(shard, &list,);
+      return value
+    }
+  }
+  /// Removes the value `id` refers to.
+  /// 
+  /// Only the shard holding the value is locked. Returns `None` if the value was
+  /// already removed.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  pub fn remove(&self, id: ShardedNodeId,) -> Option<T> {
+    let mut list = self.lock(id.shard,);
+    let value = list.remove_by_handle(id.id,).map(|(_, value,)| value,);
+
+    self.update_head(id.shard, &list,); value
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::{sync::Arc, thread,};
+
+  #[test]
+  fn test_concurrent_vec_list() {
+    let list = Arc::new(ConcurrentVecList::new(4,),);
+    let threads = (0..4).map(|thread| {
+      let list = list.clone();
+
+      thread::spawn(move || for i in 0..100 { list.push_back((thread, i,),); },)
+    }).collect::<Vec<_,>>();
+
+    for thread in threads { thread.join().expect("pushing thread panicked") }
+    assert_eq!(list.len(), 400, "`ConcurrentVecList::push_back` lost values",);
+
+    //Values from each thread come out in the order they were pushed.
+    let mut last = [None; 4];
+    while let Some((thread, i,)) = list.pop_front() {
+      assert!(last[thread] < Some(i), "`ConcurrentVecList::pop_front` popped out of order",);
+      last[thread] = Some(i);
+    }
+
+    let id = list.push_back((0, 0,),);
+    list.push_back((1, 1,),);
+    assert_eq!(list.remove(id,), Some((0, 0,)), "`ConcurrentVecList::remove` removed the wrong value",);
+    assert_eq!(list.remove(id,), None, "`ConcurrentVecList::remove` removed a value twice",);
+    assert_eq!(list.pop_front(), Some((1, 1,)), "`ConcurrentVecList::remove` removed the wrong value",);
+
+    let list = ConcurrentVecList::new(2,);
+
+    list.push_back(0,);
+    list.push_back(1,);
+
+    //Popping from the first shard must not wait on the second.
+    let _second = list.lock(1,);
+
+    assert_eq!(list.pop_front(), Some(0), "`ConcurrentVecList::pop_front` popped the wrong value",);
+  }
+}
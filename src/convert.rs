@@ -0,0 +1,194 @@
+
+use {VecList, Node, NonZeroUsize, Alloc,};
+use std::{ptr, mem,};
+use std::collections::{LinkedList, VecDeque,};
+
+impl<T: Clone, A: Alloc + Clone,> VecList<T, A,> {
+  /// Clones the values into a [`Vec`] in list order with a single allocation.
+  pub fn to_vec(&self,) -> Vec<T> {
+    let mut vec = Vec::with_capacity(self.len(),);
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr {
+      let node = unsafe { &*self.node(node,) };
+
+      vec.push((*node.value).clone(),);
+      ptr = node.next;
+    }
+
+    vec
+  }
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Moves the values into a [`Vec`] in list order with a single allocation and frees
+  /// the buffer.
+  pub fn into_vec(mut self,) -> Vec<T> {
+    let mut vec = Vec::with_capacity(self.len(),);
+    let mut ptr = self.ends.take().map(|(_, head, _,)| head);
+
+    //The values are forgotten by the list before they are moved out.
+    while let Some(node) = ptr {
+      let node = unsafe { &*self.node(node,) };
+
+      vec.push(unsafe { ptr::read(&*node.value,) },);
+      ptr = node.next;
+    }
+
+    vec
+  }
+}
+
+impl<T,> From<Vec<T>> for VecList<T,> {
+  /// Moves the values into a single allocation with the [`Node`]s laid out
+  /// contiguously in order.
+  fn from(vec: Vec<T>,) -> Self {
+    let mut list = Self::with_capacity(vec.len(),);
+
+    for value in vec {
+      let index = list.node_count;
+
+      unsafe {
+        *list.node_mut(index,) = Node::new(value, 0,);
+        if let Some(prev) = index.checked_sub(1,) { list.node_append(prev, index,) }
+      }
+      list.node_count += 1;
+    }
+
+    list.ends = NonZeroUsize::new(list.node_count,).map(|len| (len, 0, len.get() - 1,));
+
+    list
+  }
+}
+
+impl<'a, T: Clone,> From<&'a [T]> for VecList<T,> {
+  /// Clones the values into a single allocation.
+  #[inline]
+  fn from(slice: &'a [T],) -> Self { slice.iter().collect() }
+}
+
+macro_rules! array_from {
+  ($($len:expr)*) => {
+    $(impl<T,> From<[T; $len]> for VecList<T,> {
+      /// Moves the values into a single allocation.
+      fn from(array: [T; $len],) -> Self {
+        //The values are moved out so the array must not drop them.
+        let array = mem::ManuallyDrop::new(array,);
+        let mut list = Self::with_capacity(array.len(),);
+
+        unsafe {
+          for index in 0..array.len() { list.push_back(ptr::read(array.as_ptr().add(index,),),); }
+        }
+
+        list
+      }
+    })*
+  };
+}
+
+array_from! {
+  0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+  17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+impl<T,> From<VecDeque<T>> for VecList<T,> {
+  /// Moves the values into a single allocation.
+  #[inline]
+  fn from(deque: VecDeque<T>,) -> Self { deque.into_iter().collect() }
+}
+
+impl<T,> From<LinkedList<T>> for VecList<T,> {
+  /// Moves the values into a single allocation.
+  #[inline]
+  fn from(list: LinkedList<T>,) -> Self { list.into_iter().collect() }
+}
+
+impl<T, A: Alloc + Clone,> From<VecList<T, A,>> for Vec<T> {
+  #[inline]
+  fn from(list: VecList<T, A,>,) -> Self { list.into_vec() }
+}
+
+impl<T, A: Alloc + Clone,> From<VecList<T, A,>> for VecDeque<T> {
+  #[inline]
+  fn from(list: VecList<T, A,>,) -> Self { list.into_vec().into() }
+}
+
+impl<T, A: Alloc + Clone,> From<VecList<T, A,>> for LinkedList<T> {
+  #[inline]
+  fn from(list: VecList<T, A,>,) -> Self { list.into_iter().collect() }
+}
+
+impl<T: Ord, A: Alloc + Clone,> VecList<T, A,> {
+  /// Moves the values into a [`Vec`] and sorts them there.
+  /// 
+  /// Sorting contiguous values is faster than sorting by relinking [`Node`]s so this is
+  /// preferable to [`VecList::sort_by`] when the [`VecList`] is being discarded anyway.
+  pub fn into_sorted_vec(mut self,) -> Vec<T> {
+    let mut vec = Vec::with_capacity(self.len(),);
+
+    vec.extend(self.drain(..,),);
+    vec.sort();
+
+    vec
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_vec() {
+    let mut list = vec![3, 1, 2,].into_iter().collect::<VecList<i32,>>();
+
+    list.pop_front();
+    list.push_front(0,);
+    assert_eq!(list.to_vec(), vec![0, 1, 2,], "`VecList::to_vec` incorrect result",);
+    assert_eq!(list.len(), 3, "`VecList::to_vec` changed the list",);
+  }
+  #[test]
+  fn test_into_sorted_vec() {
+    let list = vec![3, 1, 2, 1,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list.into_sorted_vec(), vec![1, 1, 2, 3,], "`VecList::into_sorted_vec` incorrect result",);
+  }
+  #[test]
+  fn test_into_vec() {
+    let mut list = vec![3, 1, 2,].into_iter().collect::<VecList<i32,>>();
+
+    list.pop_front();
+    list.push_back(3,);
+
+    let vec = list.into_vec();
+
+    assert_eq!(vec, vec![1, 2, 3,], "`VecList::into_vec` incorrect result",);
+    assert_eq!(vec.capacity(), 3, "`VecList::into_vec` incorrect capacity",);
+  }
+  #[test]
+  fn test_from_vec() {
+    let list = VecList::from(vec![3, 1, 2,],);
+
+    assert_eq!(list, [3, 1, 2,], "`VecList::from` incorrect values",);
+    assert_eq!(list.capacity(), 3, "`VecList::from` incorrect capacity",);
+    assert_eq!(list.dump_layout().head, Some(0), "`VecList::from` did not lay the values out in order",);
+  }
+  #[test]
+  fn test_from_slice() {
+    assert_eq!(VecList::from(&[3, 1, 2,][..],), [3, 1, 2,], "`VecList::from` incorrect slice conversion",);
+    assert_eq!(VecList::from([3, 1, 2,],), [3, 1, 2,], "`VecList::from` incorrect array conversion",);
+    assert_eq!(VecList::from([String::from("a",)],), [String::from("a",)], "`VecList::from` incorrect owned array conversion",);
+  }
+  #[test]
+  fn test_from_collections() {
+    let list = VecList::from(vec![3, 1, 2,],);
+    let deque = VecDeque::from(list.clone(),);
+
+    assert_eq!(deque, [3, 1, 2,], "`VecDeque::from` incorrect values",);
+    assert_eq!(VecList::from(deque,), list, "`VecList::from` incorrect `VecDeque` conversion",);
+
+    let linked = LinkedList::from(list.clone(),);
+
+    assert!(linked.iter().eq(&[3, 1, 2,]), "`LinkedList::from` incorrect values",);
+    assert_eq!(VecList::from(linked,), list, "`VecList::from` incorrect `LinkedList` conversion",);
+  }
+}
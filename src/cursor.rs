@@ -0,0 +1,896 @@
+
+use {VecList, NodeId, Alloc, Global,};
+use std::iter::Extend;
+
+/// A cursor over a [`VecList`] which can move back and forth.
+/// 
+/// Like [`std::collections::linked_list::Cursor`] the cursor has a "ghost" position
+/// between the back and the front of the list which it rests on when it is not pointing
+/// at any value.
+pub struct Cursor<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] being read.
+  list: &'t VecList<T, A,>,
+  /// The index of the current [`Node`] or `None` at the ghost position.
+  current: Option<usize>,
+  /// The position of the current [`Node`] in the list; the length at the ghost position.
+  index: usize,
+}
+
+/// A cursor over a [`VecList`] which can move back and forth and edit the list.
+/// 
+/// Like [`std::collections::linked_list::CursorMut`] the cursor has a "ghost" position
+/// between the back and the front of the list which it rests on when it is not pointing
+/// at any value.
+/// 
+/// Values removed through the cursor leave the [`VecList`] uncompacted until the cursor
+/// is dropped, when the compaction policy is consulted.
+pub struct CursorMut<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] being edited.
+  list: &'t mut VecList<T, A,>,
+  /// The index of the current [`Node`] or `None` at the ghost position.
+  current: Option<usize>,
+  /// The position of the current [`Node`] in the list; the length at the ghost position.
+  index: usize,
+}
+
+/// A saved cursor position which can be turned back into a cursor over the same
+/// [`VecList`] without walking to it from the front again.
+/// 
+/// Created by [`Cursor::position`] and [`CursorMut::position`]. A position is only
+/// restored if the value it points at is still in the [`VecList`]; the value may have
+/// been moved by inserts, removals or reordering since and its index is found again by
+/// walking from it to the nearest end, see [`VecList::position_of`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub struct CursorPos {
+  /// The handle to the current value or `None` at the ghost position.
+  node: Option<NodeId>,
+}
+
+/// Finds the [`Node`] at position `target` walking from whichever of the front, the back
+/// or the current [`Node`] is closest.
+/// 
+/// Returns `None` if `target` is the ghost position.
+/// 
+/// # Params
+/// 
+/// list --- The [`VecList`] the cursor is over.  
+/// current --- The current [`Node`] of the cursor.  
+/// index --- The position of the cursor.  
+/// target --- The position to find.  
+fn seek_ptr<T, A: Alloc + Clone,>(list: &VecList<T, A,>, current: Option<usize>, index: usize, target: usize,) -> Option<usize> {
+  let (_, head, tail,) = list.ends?;
+  let len = list.len();
+
+  if target >= len { return None }
+
+  let (to_front, to_back,) = (target, len - 1 - target,);
+
+  match current {
+    Some(ptr) if index <= target && target - index <= usize::min(to_front, to_back,) => Some(list.forwards(ptr, target - index,)),
+    Some(ptr) if index > target && index - target <= usize::min(to_front, to_back,) => Some(list.backwards(ptr, index - target,)),
+    _ if to_front <= to_back => Some(list.forwards(head, to_front,)),
+    _ => Some(list.backwards(tail, to_back,)),
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> VecList<T, A,> {
+  /// Returns a [`Cursor`] pointing at the front of the [`VecList`].
+  /// 
+  /// If the [`VecList`] is empty the cursor points at the ghost position.
+  #[inline]
+  pub fn cursor_front(&'t self,) -> Cursor<'t, T, A,> {
+    Cursor { list: self, current: self.ends.map(|(_, head, _,)| head), index: 0, }
+  }
+  /// Returns a [`Cursor`] pointing at the back of the [`VecList`].
+  /// 
+  /// If the [`VecList`] is empty the cursor points at the ghost position.
+  #[inline]
+  pub fn cursor_back(&'t self,) -> Cursor<'t, T, A,> {
+    Cursor {
+      list: self,
+      current: self.ends.map(|(_, _, tail,)| tail),
+      index: self.len().saturating_sub(1,),
+    }
+  }
+  /// Returns a [`Cursor`] pointing at the value at `index`, walking from whichever end
+  /// of the [`VecList`] is closer.
+  /// 
+  /// If `index == self.len()` the cursor points at the ghost position.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the value to point at.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index > self.len()`.
+  pub fn cursor_at(&'t self, index: usize,) -> Cursor<'t, T, A,> {
+    assert!(index <= self.len(), "`VecList::cursor_at` index out of range",);
+
+    let current = if index < self.len() { Some(self.ptr(index,)) } else { None };
+
+    Cursor { list: self, current, index, }
+  }
+  /// Returns a [`CursorMut`] pointing at the value at `index`, walking from whichever
+  /// end of the [`VecList`] is closer.
+  /// 
+  /// If `index == self.len()` the cursor points at the ghost position so values can be
+  /// inserted at the back with [`CursorMut::insert_before`].
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the value to point at.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index > self.len()`.
+  pub fn cursor_at_mut(&'t mut self, index: usize,) -> CursorMut<'t, T, A,> {
+    assert!(index <= self.len(), "`VecList::cursor_at_mut` index out of range",);
+
+    let current = if index < self.len() { Some(self.ptr(index,)) } else { None };
+
+    CursorMut { list: self, current, index, }
+  }
+  /// Returns a [`Cursor`] at a saved position.
+  /// 
+  /// Returns `None` if the saved value has been removed since the position was saved.
+  /// 
+  /// # Params
+  /// 
+  /// pos --- The saved position.  
+  pub fn cursor_from(&'t self, pos: CursorPos,) -> Option<Cursor<'t, T, A,>> {
+    let (current, index,) = self.restore_pos(pos,)?;
+
+    Some(Cursor { list: self, current, index, })
+  }
+  /// Returns a [`CursorMut`] at a saved position.
+  /// 
+  /// Returns `None` if the saved value has been removed since the position was saved.
+  /// 
+  /// # Params
+  /// 
+  /// pos --- The saved position.  
+  pub fn cursor_from_mut(&'t mut self, pos: CursorPos,) -> Option<CursorMut<'t, T, A,>> {
+    let (current, index,) = self.restore_pos(pos,)?;
+
+    Some(CursorMut { list: self, current, index, })
+  }
+  /// Returns a [`Cursor`] pointing at the first value which satisfies `pred`.
+  /// 
+  /// The search walks forwards from the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn find_view<P,>(&'t self, pred: P,) -> Option<Cursor<'t, T, A,>>
+    where P: FnMut(&T,) -> bool, {
+    let (index, current,) = self.find_ptr(pred,)?;
+
+    Some(Cursor { list: self, current: Some(current), index, })
+  }
+  /// Returns a [`CursorMut`] pointing at the first value which satisfies `pred` so the
+  /// [`VecList`] can be edited around it.
+  /// 
+  /// The search walks forwards from the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn find_view_mut<P,>(&'t mut self, pred: P,) -> Option<CursorMut<'t, T, A,>>
+    where P: FnMut(&T,) -> bool, {
+    let (index, current,) = self.find_ptr(pred,)?;
+
+    Some(CursorMut { list: self, current: Some(current), index, })
+  }
+  /// Returns a [`Cursor`] pointing at the last value which satisfies `pred`.
+  /// 
+  /// The search walks backwards from the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn rfind_view<P,>(&'t self, pred: P,) -> Option<Cursor<'t, T, A,>>
+    where P: FnMut(&T,) -> bool, {
+    let (index, current,) = self.rfind_ptr(pred,)?;
+
+    Some(Cursor { list: self, current: Some(current), index, })
+  }
+  /// Returns a [`CursorMut`] pointing at the last value which satisfies `pred` so the
+  /// [`VecList`] can be edited around it.
+  /// 
+  /// The search walks backwards from the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn rfind_view_mut<P,>(&'t mut self, pred: P,) -> Option<CursorMut<'t, T, A,>>
+    where P: FnMut(&T,) -> bool, {
+    let (index, current,) = self.rfind_ptr(pred,)?;
+
+    Some(CursorMut { list: self, current: Some(current), index, })
+  }
+  /// Validates a saved position and returns its current [`Node`] and index.
+  /// 
+  /// # Params
+  /// 
+  /// pos --- The saved position.  
+  fn restore_pos(&self, pos: CursorPos,) -> Option<(Option<usize>, usize,)> {
+    match pos.node {
+      Some(id) => self.position_of(id,).map(|index| (Some(id.slot()), index,),),
+      None => Some((None, self.len(),)),
+    }
+  }
+  /// Returns a [`CursorMut`] pointing at the front of the [`VecList`].
+  /// 
+  /// If the [`VecList`] is empty the cursor points at the ghost position.
+  #[inline]
+  pub fn cursor_front_mut(&'t mut self,) -> CursorMut<'t, T, A,> {
+    let current = self.ends.map(|(_, head, _,)| head);
+    let index = if current.is_some() { 0 } else { self.len() };
+
+    CursorMut { list: self, current, index, }
+  }
+  /// Returns a [`CursorMut`] pointing at the back of the [`VecList`].
+  /// 
+  /// If the [`VecList`] is empty the cursor points at the ghost position.
+  #[inline]
+  pub fn cursor_back_mut(&'t mut self,) -> CursorMut<'t, T, A,> {
+    let current = self.ends.map(|(_, _, tail,)| tail);
+    let index = self.len().saturating_sub(1,);
+
+    CursorMut { list: self, current, index, }
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Cursor<'t, T, A,> {
+  /// Returns the position of the cursor in the [`VecList`] or `None` at the ghost
+  /// position.
+  #[inline]
+  pub fn index(&self,) -> Option<usize> { self.current.map(|_| self.index) }
+  /// Saves the position of the cursor so it can be restored later.
+  #[inline]
+  pub fn position(&self,) -> CursorPos {
+    CursorPos { node: self.current.map(|ptr| self.list.handle(ptr,)), }
+  }
+  /// Returns a reference to the value the cursor is pointing at.
+  #[inline]
+  pub fn current(&self,) -> Option<&'t T> {
+    let list = self.list;
+
+    self.current.map(|ptr| unsafe { list.value(ptr,) })
+  }
+  /// Returns a reference to the value after the current value.
+  /// 
+  /// At the ghost position this is the front of the [`VecList`].
+  pub fn peek_next(&self,) -> Option<&'t T> {
+    let list = self.list;
+    let next = match self.current {
+      Some(ptr) => unsafe { (*list.node(ptr,)).next },
+      None => list.ends.map(|(_, head, _,)| head),
+    };
+
+    next.map(|ptr| unsafe { list.value(ptr,) })
+  }
+  /// Returns a reference to the value before the current value.
+  /// 
+  /// At the ghost position this is the back of the [`VecList`].
+  pub fn peek_prev(&self,) -> Option<&'t T> {
+    let list = self.list;
+    let prev = match self.current {
+      Some(ptr) => unsafe { (*list.node(ptr,)).prev },
+      None => list.ends.map(|(_, _, tail,)| tail),
+    };
+
+    prev.map(|ptr| unsafe { list.value(ptr,) })
+  }
+  /// Moves the cursor to the next value.
+  /// 
+  /// Moving past the back of the [`VecList`] moves to the ghost position and moving
+  /// from the ghost position moves to the front of the [`VecList`].
+  pub fn move_next(&mut self,) {
+    match self.current {
+      Some(ptr) => {
+        self.current = unsafe { (*self.list.node(ptr,)).next };
+        self.index += 1;
+      },
+      None => {
+        self.current = self.list.ends.map(|(_, head, _,)| head);
+        self.index = 0;
+      },
+    }
+  }
+  /// Moves the cursor to the previous value.
+  /// 
+  /// Moving past the front of the [`VecList`] moves to the ghost position and moving
+  /// from the ghost position moves to the back of the [`VecList`].
+  pub fn move_prev(&mut self,) {
+    match self.current {
+      Some(ptr) => {
+        self.current = unsafe { (*self.list.node(ptr,)).prev };
+        self.index = if self.current.is_some() { self.index - 1 } else { self.list.len() };
+      },
+      None => {
+        self.current = self.list.ends.map(|(_, _, tail,)| tail);
+        self.index = self.list.len().saturating_sub(1,);
+      },
+    }
+  }
+  /// Moves the cursor forwards by up to `n` values, walking from whichever of the front,
+  /// the back or the current value is closest.
+  /// 
+  /// Unlike [`Cursor::move_next`] the cursor does not wrap past the ghost position.
+  /// 
+  /// Returns the number of steps actually moved.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of steps to move forwards.  
+  pub fn advance_by(&mut self, n: usize,) -> usize {
+    let target = usize::min(self.index.saturating_add(n,), self.list.len(),);
+    let moved = target - self.index;
+
+    self.current = seek_ptr(self.list, self.current, self.index, target,);
+    self.index = target;
+
+    moved
+  }
+  /// Moves the cursor backwards by up to `n` values, walking from whichever of the
+  /// front, the back or the current value is closest.
+  /// 
+  /// Unlike [`Cursor::move_prev`] the cursor does not wrap past the front.
+  /// 
+  /// Returns the number of steps actually moved.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of steps to move backwards.  
+  pub fn advance_back_by(&mut self, n: usize,) -> usize {
+    let target = self.index.saturating_sub(n,);
+    let moved = self.index - target;
+
+    self.current = seek_ptr(self.list, self.current, self.index, target,);
+    self.index = target;
+
+    moved
+  }
+  /// Moves the cursor `offset` values forwards, or backwards if `offset` is negative,
+  /// without wrapping.
+  /// 
+  /// Returns the offset actually moved.
+  /// 
+  /// # Params
+  /// 
+  /// offset --- The number of steps to move.  
+  #[inline]
+  pub fn seek(&mut self, offset: isize,) -> isize {
+    if offset < 0 { -(self.advance_back_by(offset.wrapping_neg() as usize,) as isize) }
+    else { self.advance_by(offset as usize,) as isize }
+  }
+  /// Returns a reference to the value at the front of the [`VecList`].
+  #[inline]
+  pub fn front(&self,) -> Option<&'t T> {
+    let list = self.list;
+
+    list.ends.map(|(_, head, _,)| unsafe { list.value(head,) })
+  }
+  /// Returns a reference to the value at the back of the [`VecList`].
+  #[inline]
+  pub fn back(&self,) -> Option<&'t T> {
+    let list = self.list;
+
+    list.ends.map(|(_, _, tail,)| unsafe { list.value(tail,) })
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Clone for Cursor<'t, T, A,> {
+  #[inline]
+  fn clone(&self,) -> Self { *self }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Copy for Cursor<'t, T, A,> {}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> CursorMut<'t, T, A,> {
+  /// Returns a read-only [`Cursor`] at the same position which borrows this cursor.
+  #[inline]
+  pub fn as_cursor<'a,>(&'a self,) -> Cursor<'a, T, A,> {
+    Cursor { list: self.list, current: self.current, index: self.index, }
+  }
+  /// Returns the position of the cursor in the [`VecList`] or `None` at the ghost
+  /// position.
+  #[inline]
+  pub fn index(&self,) -> Option<usize> { self.current.map(|_| self.index) }
+  /// Saves the position of the cursor so it can be restored later.
+  #[inline]
+  pub fn position(&self,) -> CursorPos {
+    CursorPos { node: self.current.map(|ptr| self.list.handle(ptr,)), }
+  }
+  /// Returns a mutable reference to the value the cursor is pointing at.
+  #[inline]
+  pub fn current(&mut self,) -> Option<&mut T> {
+    let list = &mut *self.list;
+
+    self.current.map(move |ptr| unsafe { &mut *(*list.node_mut(ptr,)).value })
+  }
+  /// Returns a mutable reference to the value after the current value.
+  /// 
+  /// At the ghost position this is the front of the [`VecList`].
+  pub fn peek_next(&mut self,) -> Option<&mut T> {
+    let next = match self.current {
+      Some(ptr) => unsafe { (*self.list.node(ptr,)).next },
+      None => self.list.ends.map(|(_, head, _,)| head),
+    };
+    let list = &mut *self.list;
+
+    next.map(move |ptr| unsafe { &mut *(*list.node_mut(ptr,)).value })
+  }
+  /// Returns a mutable reference to the value before the current value.
+  /// 
+  /// At the ghost position this is the back of the [`VecList`].
+  pub fn peek_prev(&mut self,) -> Option<&mut T> {
+    let prev = match self.current {
+      Some(ptr) => unsafe { (*self.list.node(ptr,)).prev },
+      None => self.list.ends.map(|(_, _, tail,)| tail),
+    };
+    let list = &mut *self.list;
+
+    prev.map(move |ptr| unsafe { &mut *(*list.node_mut(ptr,)).value })
+  }
+  /// Moves the cursor to the next value.
+  /// 
+  /// Moving past the back of the [`VecList`] moves to the ghost position and moving
+  /// from the ghost position moves to the front of the [`VecList`].
+  pub fn move_next(&mut self,) {
+    match self.current {
+      Some(ptr) => {
+        self.current = unsafe { (*self.list.node(ptr,)).next };
+        self.index += 1;
+      },
+      None => {
+        self.current = self.list.ends.map(|(_, head, _,)| head);
+        self.index = 0;
+      },
+    }
+  }
+  /// Moves the cursor to the previous value.
+  /// 
+  /// Moving past the front of the [`VecList`] moves to the ghost position and moving
+  /// from the ghost position moves to the back of the [`VecList`].
+  pub fn move_prev(&mut self,) {
+    match self.current {
+      Some(ptr) => {
+        self.current = unsafe { (*self.list.node(ptr,)).prev };
+        self.index = if self.current.is_some() { self.index - 1 } else { self.list.len() };
+      },
+      None => {
+        self.current = self.list.ends.map(|(_, _, tail,)| tail);
+        self.index = self.list.len().saturating_sub(1,);
+      },
+    }
+  }
+  /// Moves the cursor forwards by up to `n` values, walking from whichever of the front,
+  /// the back or the current value is closest.
+  /// 
+  /// Unlike [`CursorMut::move_next`] the cursor does not wrap past the ghost position.
+  /// 
+  /// Returns the number of steps actually moved.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of steps to move forwards.  
+  pub fn advance_by(&mut self, n: usize,) -> usize {
+    let target = usize::min(self.index.saturating_add(n,), self.list.len(),);
+    let moved = target - self.index;
+
+    self.current = seek_ptr(self.list, self.current, self.index, target,);
+    self.index = target;
+
+    moved
+  }
+  /// Moves the cursor backwards by up to `n` values, walking from whichever of the
+  /// front, the back or the current value is closest.
+  /// 
+  /// Unlike [`CursorMut::move_prev`] the cursor does not wrap past the front.
+  /// 
+  /// Returns the number of steps actually moved.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of steps to move backwards.  
+  pub fn advance_back_by(&mut self, n: usize,) -> usize {
+    let target = self.index.saturating_sub(n,);
+    let moved = self.index - target;
+
+    self.current = seek_ptr(self.list, self.current, self.index, target,);
+    self.index = target;
+
+    moved
+  }
+  /// Moves the cursor `offset` values forwards, or backwards if `offset` is negative,
+  /// without wrapping.
+  /// 
+  /// Returns the offset actually moved.
+  /// 
+  /// # Params
+  /// 
+  /// offset --- The number of steps to move.  
+  #[inline]
+  pub fn seek(&mut self, offset: isize,) -> isize {
+    if offset < 0 { -(self.advance_back_by(offset.wrapping_neg() as usize,) as isize) }
+    else { self.advance_by(offset as usize,) as isize }
+  }
+  /// Inserts `value` before the current value.
+  /// 
+  /// At the ghost position `value` is inserted at the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to insert.  
+  #[inline]
+  pub fn insert_before(&mut self, value: T,) {
+    self.list.link_before(self.current, value,);
+    self.index += 1;
+  }
+  /// Inserts `value` after the current value.
+  /// 
+  /// At the ghost position `value` is inserted at the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to insert.  
+  #[inline]
+  pub fn insert_after(&mut self, value: T,) {
+    match self.current {
+      Some(ptr) => {
+        let next = unsafe { (*self.list.node(ptr,)).next };
+
+        self.list.link_before(next, value,);
+      },
+      None => {
+        self.list.push_front(value,);
+        self.index += 1;
+      },
+    }
+  }
+  /// Removes the current value and moves the cursor to the next value.
+  /// 
+  /// Returns `None` at the ghost position. Removing the back of the [`VecList`] moves
+  /// the cursor to the ghost position.
+  pub fn remove_current(&mut self,) -> Option<T> {
+    let ptr = self.current?;
+
+    self.current = unsafe { (*self.list.node(ptr,)).next };
+
+    Some(self.list.unlink_node(ptr,))
+  }
+  /// Moves every value of `other` into the [`VecList`] before the current value.
+  /// 
+  /// The values are moved with a single reservation and linked in once. At the ghost
+  /// position the values are appended to the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// other --- The [`VecList`] to move the values out of.  
+  pub fn splice_before(&mut self, mut other: VecList<T, A,>,) {
+    if let Some((start, end, count,)) = self.list.adopt_nodes(&mut other,) {
+      unsafe { self.list.link_segment_before(self.current, start, end, count,) }
+      self.index += count;
+    }
+  }
+  /// Moves every value of `other` into the [`VecList`] after the current value.
+  /// 
+  /// The values are moved with a single reservation and linked in once. At the ghost
+  /// position the values are prepended to the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// other --- The [`VecList`] to move the values out of.  
+  pub fn splice_after(&mut self, mut other: VecList<T, A,>,) {
+    if let Some((start, end, count,)) = self.list.adopt_nodes(&mut other,) {
+      let next = match self.current {
+        Some(ptr) => unsafe { (*self.list.node(ptr,)).next },
+        None => {
+          self.index += count;
+          self.list.ends.map(|(_, head, _,)| head)
+        },
+      };
+
+      unsafe { self.list.link_segment_before(next, start, end, count,) }
+    }
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> CursorMut<'t, T, A,> {
+  /// Returns a reference to the value at the front of the [`VecList`].
+  #[inline]
+  pub fn front(&self,) -> Option<&T> {
+    self.list.ends.map(|(_, head, _,)| unsafe { self.list.value(head,) })
+  }
+  /// Returns a mutable reference to the value at the front of the [`VecList`].
+  #[inline]
+  pub fn front_mut(&mut self,) -> Option<&mut T> {
+    let list = &mut *self.list;
+
+    list.ends.map(move |(_, head, _,)| unsafe { &mut *(*list.node_mut(head,)).value })
+  }
+  /// Returns a reference to the value at the back of the [`VecList`].
+  #[inline]
+  pub fn back(&self,) -> Option<&T> {
+    self.list.ends.map(|(_, _, tail,)| unsafe { self.list.value(tail,) })
+  }
+  /// Returns a mutable reference to the value at the back of the [`VecList`].
+  #[inline]
+  pub fn back_mut(&mut self,) -> Option<&mut T> {
+    let list = &mut *self.list;
+
+    list.ends.map(move |(_, _, tail,)| unsafe { &mut *(*list.node_mut(tail,)).value })
+  }
+  /// Pushes `value` onto the front of the [`VecList`] without moving the cursor.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to push.  
+  #[inline]
+  pub fn push_front(&mut self, value: T,) {
+    self.list.push_front(value,);
+    self.index += 1;
+  }
+  /// Pushes `value` onto the back of the [`VecList`] without moving the cursor.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to push.  
+  #[inline]
+  pub fn push_back(&mut self, value: T,) {
+    self.list.push_back(value,);
+    if self.current.is_none() { self.index += 1 }
+  }
+  /// Removes the value at the front of the [`VecList`].
+  /// 
+  /// If the cursor is pointing at the front it moves to the next value.
+  pub fn pop_front(&mut self,) -> Option<T> {
+    let (_, head, _,) = self.list.ends?;
+
+    if self.current == Some(head) {
+      self.current = unsafe { (*self.list.node(head,)).next };
+    } else { self.index -= 1 }
+
+    Some(self.list.unlink_node(head,))
+  }
+  /// Removes the value at the back of the [`VecList`].
+  /// 
+  /// If the cursor is pointing at the back it moves to the ghost position.
+  pub fn pop_back(&mut self,) -> Option<T> {
+    let (_, _, tail,) = self.list.ends?;
+
+    if self.current == Some(tail) { self.current = None }
+    else if self.current.is_none() { self.index -= 1 }
+
+    Some(self.list.unlink_node(tail,))
+  }
+  /// Splits the [`VecList`] after the current value.
+  /// 
+  /// Returns a new [`VecList`] containing the values after the current value. At the
+  /// ghost position every value is moved.
+  pub fn split_after(&mut self,) -> VecList<T, A,> {
+    match self.current {
+      Some(_) => {
+        let back = self.list.split_off(self.index + 1,);
+
+        //Splitting may compact the values so find the current value again.
+        self.current = self.list.ends.map(|(_, _, tail,)| tail);
+
+        back
+      },
+      None => {
+        self.index = 0;
+        self.list.split_off(0,)
+      },
+    }
+  }
+  /// Splits the [`VecList`] before the current value.
+  /// 
+  /// Returns a new [`VecList`] containing the values before the current value. At the
+  /// ghost position every value is moved.
+  pub fn split_before(&mut self,) -> VecList<T, A,> {
+    let mut front = self.list.empty_like(self.index,);
+
+    front.extend(self.list.drain(..self.index),);
+    self.index = 0;
+    //Draining may compact the values so find the current value again.
+    self.current = self.current.and(self.list.ends.map(|(_, head, _,)| head),);
+
+    front
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Drop for CursorMut<'t, T, A,> {
+  #[inline]
+  fn drop(&mut self,) { self.list.after_removal() }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Extend<T> for CursorMut<'t, T, A,> {
+  /// Inserts the values before the current value in order.
+  #[inline]
+  fn extend<I,>(&mut self, iter: I,)
+    where I: IntoIterator<Item = T>, {
+    for value in iter { self.insert_before(value,) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cursor() {
+    let list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let mut cursor = list.cursor_front();
+
+    assert_eq!(cursor.peek_prev(), None, "`Cursor::peek_prev` stepped past the front",);
+    cursor.move_next();
+    assert_eq!((cursor.peek_prev(), cursor.current(), cursor.peek_next(),), (Some(&0), Some(&1), Some(&2),),
+      "`Cursor::peek_*` incorrect values",);
+
+    let mut ghost = list.cursor_back();
+
+    ghost.move_next();
+    assert_eq!(ghost.index(), None, "`Cursor::move_next` did not reach the ghost",);
+    assert_eq!((ghost.peek_prev(), ghost.peek_next(),), (Some(&2), Some(&0),), "`Cursor::peek_*` incorrect values at the ghost",);
+    ghost.move_prev();
+    assert_eq!(ghost.index(), Some(2), "`Cursor::move_prev` incorrect index",);
+  }
+  #[test]
+  fn test_cursor_at() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!((list.cursor_front().index(), list.cursor_front().current(),), (Some(0), Some(&0),), "`VecList::cursor_front` incorrect position",);
+    assert_eq!((list.cursor_back().index(), list.cursor_back().current(),), (Some(2), Some(&2),), "`VecList::cursor_back` incorrect position",);
+    assert_eq!(list.cursor_at(1,).current(), Some(&1), "`VecList::cursor_at` incorrect value",);
+    assert_eq!(list.cursor_at(3,).index(), None, "`VecList::cursor_at` did not point at the ghost",);
+    assert_eq!(list.cursor_at_mut(2,).current(), Some(&mut 2), "`VecList::cursor_at_mut` incorrect value",);
+    assert_eq!(VecList::<i32>::new().cursor_front().index(), None, "`VecList::cursor_front` did not point at the ghost when empty",);
+  }
+  #[test]
+  fn test_cursor_seek() {
+    let list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let mut cursor = list.cursor_front();
+
+    assert_eq!(cursor.advance_by(2,), 2, "`Cursor::advance_by` moved the wrong distance",);
+    assert_eq!(cursor.current(), Some(&2), "`Cursor::advance_by` incorrect value",);
+    assert_eq!(cursor.seek(5,), 1, "`Cursor::seek` moved past the ghost",);
+    assert_eq!(cursor.index(), None, "`Cursor::seek` did not reach the ghost",);
+    assert_eq!(cursor.seek(-2,), -2, "`Cursor::seek` moved the wrong distance",);
+    assert_eq!(cursor.current(), Some(&1), "`Cursor::seek` incorrect value",);
+    assert_eq!(cursor.advance_back_by(4,), 1, "`Cursor::advance_back_by` moved past the front",);
+    assert_eq!(cursor.current(), Some(&0), "`Cursor::advance_back_by` did not stop at the front",);
+  }
+  #[test]
+  fn test_cursor_mut() {
+    let mut list = vec![0, 3,].into_iter().collect::<VecList<i32,>>();
+
+    {
+      let mut cursor = list.cursor_front_mut();
+
+      cursor.move_next();
+      assert_eq!(cursor.index(), Some(1), "`CursorMut::move_next` incorrect index",);
+      cursor.extend(vec![1, 2,],);
+      assert_eq!(cursor.index(), Some(3), "`CursorMut::extend` incorrect index",);
+      assert_eq!(cursor.current(), Some(&mut 3), "`CursorMut::extend` moved the cursor",);
+
+      cursor.move_next();
+      assert_eq!(cursor.index(), None, "`CursorMut::move_next` did not reach the ghost",);
+      cursor.insert_after(-1,);
+      cursor.extend(vec![4,],);
+      cursor.move_prev();
+      assert_eq!(cursor.current(), Some(&mut 4), "`CursorMut::move_prev` incorrect value",);
+      assert_eq!(cursor.index(), Some(5), "`CursorMut::move_prev` incorrect index",);
+    }
+
+    list.cursor_at_mut(6,).insert_before(5,);
+    list.pop_back();
+    assert_eq!(list.len(), 6, "`CursorMut` inserted the wrong number of values",);
+    assert_eq!(list.to_string(), "-1, 0, 1, 2, 3, 4", "`CursorMut` inserted values out of order",);
+
+    let (front, back,) = {
+      let mut cursor = list.cursor_back_mut();
+
+      cursor.move_prev();
+      assert_eq!(cursor.peek_next(), Some(&mut 4), "`CursorMut::peek_next` incorrect value",);
+      assert_eq!(cursor.pop_front(), Some(-1), "`CursorMut::pop_front` incorrect value",);
+      assert_eq!(cursor.pop_back(), Some(4), "`CursorMut::pop_back` incorrect value",);
+      assert_eq!(cursor.index(), Some(3), "`CursorMut::pop_front` did not update the index",);
+      cursor.push_front(-1,);
+      *cursor.back_mut().expect("`CursorMut::back_mut` returned `None`") += 1;
+
+      let back = cursor.split_after();
+      let front = cursor.split_before();
+
+      assert_eq!(cursor.index(), Some(0), "`CursorMut::split_before` did not update the index",);
+      assert_eq!(cursor.current(), Some(&mut 4), "`CursorMut::split_before` moved the cursor",);
+
+      (front, back,)
+    };
+
+    assert_eq!(front, [-1, 0, 1, 2,], "`CursorMut::split_before` incorrect values",);
+    assert_eq!(back, [], "`CursorMut::split_after` incorrect values",);
+    assert_eq!(list, [4,], "`CursorMut::split_*` left the wrong values",);
+  }
+  #[test]
+  fn test_cursor_splice() {
+    let mut list = vec![0, 1,].into_iter().collect::<VecList<i32,>>();
+
+    {
+      let mut cursor = list.cursor_at_mut(1,);
+
+      cursor.splice_after(vec![4, 5,].into_iter().collect(),);
+      cursor.splice_before(vec![2, 3,].into_iter().collect(),);
+      assert_eq!(cursor.index(), Some(3), "`CursorMut::splice_before` incorrect index",);
+      assert_eq!(cursor.current(), Some(&mut 1), "`CursorMut::splice_before` moved the cursor",);
+      cursor.splice_after(VecList::new(),);
+      assert_eq!(cursor.peek_next(), Some(&mut 4), "`CursorMut::splice_after` changed the list when splicing an empty list",);
+    }
+    assert_eq!(list, [0, 2, 3, 1, 4, 5,], "`CursorMut::splice_*` incorrect values",);
+
+    let mut empty = VecList::new();
+
+    empty.cursor_front_mut().splice_before(list,);
+    assert_eq!(empty, [0, 2, 3, 1, 4, 5,], "`CursorMut::splice_before` incorrect values when empty",);
+  }
+  #[test]
+  fn test_remove_current() {
+    let mut list = vec![0, 2, 3, 1, 4, 5,].into_iter().collect::<VecList<i32,>>();
+
+    {
+      let mut cursor = list.cursor_front_mut();
+
+      //Remove the odd values while scanning.
+      while let Some(&mut value) = cursor.current() {
+        if value % 2 == 1 { cursor.remove_current(); } else { cursor.move_next() }
+      }
+      assert_eq!(cursor.remove_current(), None, "`CursorMut::remove_current` removed at the ghost",);
+      assert_eq!(cursor.index(), None, "`CursorMut::remove_current` left the ghost",);
+    }
+    assert_eq!(list, [0, 2, 4,], "`CursorMut::remove_current` removed the wrong values",);
+  }
+  #[test]
+  fn test_find_view() {
+    let mut list = vec![0, 4,].into_iter().collect::<VecList<i32,>>();
+
+    {
+      let mut cursor = list.find_view_mut(|&value,| value > 0,).expect("`VecList::find_view_mut` missed a value");
+
+      assert_eq!(cursor.index(), Some(1), "`VecList::find_view_mut` incorrect index",);
+      cursor.insert_after(3,);
+    }
+    assert_eq!(list, [0, 4, 3,], "`VecList::find_view_mut` incorrect position",);
+    assert_eq!(list.find_view(|&value,| value > 0,).and_then(|cursor,| cursor.index(),), Some(1), "`VecList::find_view` incorrect position",);
+    assert!(list.find_view(|&value,| value > 4,).is_none(), "`VecList::find_view` found a missing value",);
+
+    let cursor = list.rfind_view(|&value,| value > 0,).expect("`VecList::rfind_view` missed a value");
+
+    assert_eq!((cursor.index(), cursor.current(),), (Some(2), Some(&3),), "`VecList::rfind_view` incorrect position",);
+    assert_eq!(cursor.peek_prev(), Some(&4), "`VecList::rfind_view` cursor cannot navigate",);
+  }
+  #[test]
+  fn test_cursor_pos() {
+    let mut list = (0..6).collect::<VecList<i32,>>();
+    let (pos, ghost,) = (list.cursor_at(1,).position(), list.cursor_at(6,).position(),);
+    let restored = list.cursor_from(pos,).expect("`VecList::cursor_from` rejected a valid position");
+
+    assert_eq!((restored.index(), restored.current(),), (Some(1), Some(&1),), "`VecList::cursor_from` incorrect position",);
+
+    list.sort_by(|lhs, rhs,| rhs.cmp(lhs,),);
+    assert_eq!(list.cursor_from(pos,).and_then(|cursor,| cursor.index(),), Some(4),
+      "`VecList::cursor_from` kept a stale index after a sort",);
+    list.push_front(6,);
+    assert_eq!(list.cursor_from(pos,).and_then(|cursor,| cursor.index(),), Some(5),
+      "`VecList::cursor_from` kept a stale index after an insert",);
+
+    let cursor = list.cursor_from(ghost,).expect("`VecList::cursor_from` rejected the ghost position");
+
+    assert_eq!((cursor.index(), cursor.peek_prev(),), (None, Some(&0),), "`VecList::cursor_from` incorrect ghost position",);
+
+    list.cursor_from_mut(pos,).expect("`VecList::cursor_from_mut` rejected a valid position").remove_current();
+    assert!(list.cursor_from_mut(pos,).is_none(), "`VecList::cursor_from_mut` accepted a removed position",);
+  }
+}
@@ -0,0 +1,82 @@
+
+use {VecList, Alloc, Global,};
+use std::fmt;
+
+/// Displays the values of a [`VecList`] joined by a separator.
+/// 
+/// Created by [`VecList::display_with`].
+pub struct DisplayWith<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] being displayed.
+  list: &'t VecList<T, A,>,
+  /// The separator placed between values.
+  sep: &'t str,
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Returns an adapter which displays the values of the [`VecList`] joined by `sep`.
+  /// 
+  /// # Params
+  /// 
+  /// sep --- The separator to place between values.  
+  #[inline]
+  pub fn display_with<'t,>(&'t self, sep: &'t str,) -> DisplayWith<'t, T, A,> {
+    DisplayWith { list: self, sep, }
+  }
+}
+
+impl<'t, T: 't + fmt::Display, A: 't + Alloc + Clone,> fmt::Display for DisplayWith<'t, T, A,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    let mut ptr = self.list.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr {
+      let node = unsafe { &*self.list.node(node,) };
+
+      //Only separate values after the first.
+      if node.prev.is_some() { fmt.write_str(self.sep,)? }
+      fmt::Display::fmt(&*node.value, fmt,)?;
+
+      ptr = node.next;
+    }
+
+    Ok(())
+  }
+}
+
+impl<T: fmt::Display, A: Alloc + Clone,> fmt::Display for VecList<T, A,> {
+  /// Displays the values of the [`VecList`] separated by `", "`.
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt::Display::fmt(&self.display_with(", ",), fmt,)
+  }
+}
+
+impl<T: fmt::Debug, A: Alloc + Clone,> fmt::Debug for VecList<T, A,> {
+  /// Formats the values of the [`VecList`] as a list in list order.
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_list().entries(self,).finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    let list = vec![1, 2, 3,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list.to_string(), "1, 2, 3", "`VecList` displayed incorrectly",);
+    assert_eq!(list.display_with(" -> ",).to_string(), "1 -> 2 -> 3", "`VecList::display_with` displayed incorrectly",);
+    assert_eq!(VecList::<i32,>::new().to_string(), "", "empty `VecList` displayed incorrectly",);
+  }
+  #[test]
+  fn test_debug() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<i32,>>();
+
+    list.pop_front();
+    list.push_back(4,);
+    assert_eq!(format!("{:?}", list,), "[2, 3, 4]", "`VecList` debugged incorrectly",);
+    assert_eq!(format!("{:?}", VecList::<i32,>::new(),), "[]", "empty `VecList` debugged incorrectly",);
+  }
+}
@@ -0,0 +1,19 @@
+
+use std::{fmt, error::Error,};
+
+/// An error returned when a [`VecList`] is paired with a sequence of a different length.
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub struct LengthMismatch {
+  /// The length of the [`VecList`].
+  pub expected: usize,
+  /// The length of the sequence it was paired with.
+  pub found: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    write!(fmt, "expected a sequence of length {} but found length {}", self.expected, self.found,)
+  }
+}
+
+impl Error for LengthMismatch {}
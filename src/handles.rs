@@ -0,0 +1,417 @@
+
+use {VecList, Alloc,};
+use std::{num::NonZeroU64, ops::{Index, IndexMut,},};
+
+/// An opaque handle to a value inside a [`VecList`].
+/// 
+/// A [`NodeId`] refers to the slot in the underlying buffer which stores the value so
+/// it stays the same while the value is moved around the list. Each slot counts how
+/// many times it has been reused so a [`NodeId`] to a removed value is never mistaken
+/// for a handle to a value which was later stored in the same slot.
+/// 
+/// A [`NodeId`] packs the slot into the high 32 bits and the low 32 bits of the
+/// generation into the low 32 bits of a [`NonZeroU64`] so that `Option<NodeId>` is
+/// the same size as a `u64`. Handles are ordered by slot.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug,)]
+pub struct NodeId(NonZeroU64,);
+
+impl NodeId {
+  /// Packs a slot and generation into a [`NodeId`].
+  /// 
+  /// # Params
+  /// 
+  /// slot --- The index of the slot in the buffer.  
+  /// generation --- The generation of the slot.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `slot >= u32::max_value()`.
+  #[inline]
+  pub(crate) fn new(slot: usize, generation: usize,) -> Self {
+    assert!(slot < u32::max_value() as usize, "`NodeId::new` slot out of range",);
+
+    //The slot is offset by one so the packed value is never zero.
+    let raw = ((slot as u64 + 1) << 32) | (generation as u32 as u64);
+
+    NodeId(unsafe { NonZeroU64::new_unchecked(raw,) },)
+  }
+  /// Returns the index of the slot in the buffer.
+  #[inline]
+  pub(crate) fn slot(self,) -> usize { ((self.0.get() >> 32) - 1) as usize }
+  /// Returns the low 32 bits of the generation of the slot.
+  #[inline]
+  pub(crate) fn generation(self,) -> u32 { self.0.get() as u32 }
+  /// Returns the packed representation of the [`NodeId`] for storing in side tables.
+  #[inline]
+  pub fn to_raw(self,) -> u64 { self.0.get() }
+  /// Reconstructs a [`NodeId`] from the value returned by [`NodeId::to_raw`].
+  /// 
+  /// Returns `None` if `raw` is zero; any other value is accepted and checked when the
+  /// handle is used.
+  /// 
+  /// # Params
+  /// 
+  /// raw --- The packed representation of the [`NodeId`].  
+  #[inline]
+  pub fn from_raw(raw: u64,) -> Option<Self> {
+    NonZeroU64::new(raw,).map(NodeId,)
+  }
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Gets the [`NodeId`] for the allocated [`Node`] at `ptr`.
+  /// 
+  /// # Params
+  /// 
+  /// ptr --- The index of the [`Node`] in `buf`.  
+  #[inline]
+  pub(crate) fn handle(&self, ptr: usize,) -> NodeId {
+    NodeId::new(ptr, unsafe { (*self.node(ptr,)).generation },)
+  }
+  /// Returns `true` if `id` refers to a value which is still in the [`VecList`].
+  /// 
+  /// Only allocated slots have an even generation so a handle forged with
+  /// [`NodeId::from_raw`] can never refer to an empty slot.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to check.  
+  #[inline]
+  pub fn is_valid_handle(&self, id: NodeId,) -> bool {
+    id.slot() < self.node_count
+      && id.generation() & 1 == 0
+      && unsafe { (*self.node(id.slot(),)).generation } as u32 == id.generation()
+  }
+  /// Returns a reference to the value `id` refers to or `None` if `id` is not a valid
+  /// handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  #[inline]
+  pub fn get_by_handle(&self, id: NodeId,) -> Option<&T> {
+    if self.is_valid_handle(id,) { Some(unsafe { self.value(id.slot(),) }) }
+    else { None }
+  }
+  /// Returns a mutable reference to the value `id` refers to or `None` if `id` is not a
+  /// valid handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  #[inline]
+  pub fn get_by_handle_mut(&mut self, id: NodeId,) -> Option<&mut T> {
+    if self.is_valid_handle(id,) { Some(unsafe { &mut *(*self.node_mut(id.slot(),)).value }) }
+    else { None }
+  }
+  /// Removes the value `id` refers to from the [`VecList`] without walking the list.
+  /// 
+  /// Returns `None` if `id` is not a valid handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  pub fn remove_by_handle(&mut self, id: NodeId,) -> Option<T> {
+    if !self.is_valid_handle(id,) { return None }
+
+    let value = self.unlink_node(id.slot(),);
+
+    self.after_removal(); Some(value)
+  }
+  /// Returns the current position of the value `id` refers to or `None` if `id` is not
+  /// a valid handle.
+  /// 
+  /// The list is walked towards both ends at once so this takes
+  /// `O(min(index, len - index))` time.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  pub fn position_of(&self, id: NodeId,) -> Option<usize> {
+    if !self.is_valid_handle(id,) { return None }
+
+    let (mut back, mut front,) = (Some(id.slot()), Some(id.slot()),);
+    let mut steps = 0;
+
+    loop {
+      back = back.and_then(|ptr| unsafe { (*self.node(ptr,)).prev },);
+      if back.is_none() { return Some(steps) }
+
+      front = front.and_then(|ptr| unsafe { (*self.node(ptr,)).next },);
+      if front.is_none() { return Some(self.len() - 1 - steps) }
+
+      steps += 1;
+    }
+  }
+  /// Relinks the value `id` refers to at the front of the [`VecList`] without moving it
+  /// in memory so `id` stays valid.
+  /// 
+  /// Returns `false` if `id` is not a valid handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  pub fn move_to_front(&mut self, id: NodeId,) -> bool {
+    if !self.is_valid_handle(id,) { return false }
+
+    unsafe {
+      self.unlink_segment(id.slot(), id.slot(), 1,);
+
+      let head = self.ends.map(|(_, head, _,)| head);
+
+      self.link_segment_before(head, id.slot(), id.slot(), 1,);
+    }
+
+    true
+  }
+  /// Relinks the value `id` refers to at the back of the [`VecList`] without moving it
+  /// in memory so `id` stays valid.
+  /// 
+  /// Returns `false` if `id` is not a valid handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to the value.  
+  pub fn move_to_back(&mut self, id: NodeId,) -> bool {
+    if !self.is_valid_handle(id,) { return false }
+
+    unsafe {
+      self.unlink_segment(id.slot(), id.slot(), 1,);
+      self.link_segment_before(None, id.slot(), id.slot(), 1,);
+    }
+
+    true
+  }
+  /// Returns the handle to the value at the front of the [`VecList`].
+  #[inline]
+  pub fn front_handle(&self,) -> Option<NodeId> {
+    self.ends.map(|(_, head, _,)| self.handle(head,))
+  }
+  /// Returns the handle to the value at the back of the [`VecList`].
+  #[inline]
+  pub fn back_handle(&self,) -> Option<NodeId> {
+    self.ends.map(|(_, _, tail,)| self.handle(tail,))
+  }
+  /// Returns the handle to the value after the value `id` refers to.
+  /// 
+  /// Returns `None` if `id` is the back of the [`VecList`] or is not a valid handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to step forwards from.  
+  #[inline]
+  pub fn next_handle(&self, id: NodeId,) -> Option<NodeId> {
+    if !self.is_valid_handle(id,) { return None }
+
+    unsafe { (*self.node(id.slot(),)).next }.map(|next| self.handle(next,))
+  }
+  /// Returns the handle to the value before the value `id` refers to.
+  /// 
+  /// Returns `None` if `id` is the front of the [`VecList`] or is not a valid handle.
+  /// 
+  /// # Params
+  /// 
+  /// id --- The handle to step backwards from.  
+  #[inline]
+  pub fn prev_handle(&self, id: NodeId,) -> Option<NodeId> {
+    if !self.is_valid_handle(id,) { return None }
+
+    unsafe { (*self.node(id.slot(),)).prev }.map(|prev| self.handle(prev,))
+  }
+}
+
+impl<T, A: Alloc + Clone,> Index<NodeId> for VecList<T, A,> {
+  type Output = T;
+
+  /// Returns a reference to the value `id` refers to.
+  /// 
+  /// # Panics
+  /// 
+  /// * If `id` is not a valid handle.
+  #[inline]
+  fn index(&self, id: NodeId,) -> &T {
+    self.get_by_handle(id,).expect("`VecList::index` stale handle",)
+  }
+}
+
+impl<T, A: Alloc + Clone,> IndexMut<NodeId> for VecList<T, A,> {
+  /// Returns a mutable reference to the value `id` refers to.
+  /// 
+  /// # Panics
+  /// 
+  /// * If `id` is not a valid handle.
+  #[inline]
+  fn index_mut(&mut self, id: NodeId,) -> &mut T {
+    self.get_by_handle_mut(id,).expect("`VecList::index_mut` stale handle",)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashMap;
+
+  #[test]
+  fn test_handles() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+    let middle = list.next_handle(front,).expect("`VecList::next_handle` returned `None`");
+
+    assert_eq!(list.prev_handle(front,), None, "`VecList::prev_handle` stepped past the front",);
+    assert_eq!(list.next_handle(back,), None, "`VecList::next_handle` stepped past the back",);
+    assert_eq!(list.next_handle(middle,), Some(back), "`VecList::next_handle` returned the wrong handle",);
+    assert_eq!(list.prev_handle(back,), Some(middle), "`VecList::prev_handle` returned the wrong handle",);
+
+    list.pop_front();
+    assert_eq!(list.next_handle(front,), None, "`VecList::next_handle` accepted a removed handle",);
+    assert_eq!(list.front_handle(), Some(middle), "`VecList::front_handle` was not updated",);
+
+    let back = list.push_back(3,);
+    let front = list.push_front(0,);
+
+    assert_eq!(list.back_handle(), Some(back), "`VecList::push_back` returned the wrong handle",);
+    assert_eq!(list.front_handle(), Some(front), "`VecList::push_front` returned the wrong handle",);
+    assert_eq!(list[back], 3, "`VecList::push_back` returned a handle to the wrong value",);
+  }
+  #[test]
+  fn test_is_valid_handle() {
+    let mut list = vec![0, 1,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+
+    assert!(list.is_valid_handle(front,), "`VecList::is_valid_handle` rejected a valid handle",);
+    list.pop_front();
+    assert!(!list.is_valid_handle(front,), "`VecList::is_valid_handle` accepted a removed handle",);
+
+    //Forge a handle with the generation of the empty slot.
+    let forged = NodeId::from_raw(front.to_raw() + 1,).expect("`NodeId::from_raw` returned `None`");
+
+    assert!(!list.is_valid_handle(forged,), "`VecList::is_valid_handle` accepted an empty slot",);
+
+    //Reusing the slot must not revive the old handle.
+    let reused = list.push_back(2,);
+
+    assert_eq!(reused.slot(), front.slot(), "`VecList::push_back` did not reuse the empty slot",);
+    assert!(!list.is_valid_handle(front,), "`VecList::is_valid_handle` accepted a reused slot",);
+  }
+  #[test]
+  fn test_node_id() {
+    let mut list = vec![0, 1,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    assert_eq!(NodeId::from_raw(front.to_raw(),), Some(front), "`NodeId::from_raw` did not round trip",);
+    assert_eq!(NodeId::from_raw(0,), None, "`NodeId::from_raw` accepted zero",);
+    assert_eq!(::std::mem::size_of::<Option<NodeId>>(), 8, "`Option<NodeId>` is not compact",);
+
+    let mut names = HashMap::new();
+
+    names.insert(front, "front",);
+    names.insert(back, "back",);
+    list.pop_front();
+    names.insert(list.push_front(2,), "reused",);
+    assert_eq!((names.len(), names[&front],), (3, "front",), "`NodeId` is not usable as a map key",);
+  }
+  #[test]
+  fn test_get_by_handle() {
+    let mut list = vec![0, 1,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    *list.get_by_handle_mut(back,).expect("`VecList::get_by_handle_mut` returned `None`") += 10;
+    assert_eq!(list.get_by_handle(back,), Some(&11), "`VecList::get_by_handle` returned the wrong value",);
+    list.pop_front();
+    assert_eq!(list.get_by_handle(front,), None, "`VecList::get_by_handle` accepted a removed handle",);
+    assert_eq!(list.get_by_handle_mut(front,), None, "`VecList::get_by_handle_mut` accepted a removed handle",);
+  }
+  #[test]
+  fn test_index_handle() {
+    let mut list = vec![0, 1,].into_iter().collect::<VecList<i32,>>();
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    list[back] += 10;
+    assert_eq!(list[back], 11, "`VecList::index_mut` did not write the value",);
+  }
+  #[test]
+  #[should_panic]
+  fn test_index_removed_handle() {
+    let mut list = vec![0, 1,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+
+    list.pop_front();
+    let _ = list[front];
+  }
+  #[test]
+  fn test_remove_by_handle() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+    //Forge a handle with the generation of the empty slot.
+    let forged = NodeId::from_raw(front.to_raw() + 1,).expect("`NodeId::from_raw` returned `None`");
+
+    assert_eq!(list.remove_by_handle(forged,), None, "`VecList::remove_by_handle` accepted an empty slot",);
+    assert_eq!(list.remove_by_handle(back,), Some(2), "`VecList::remove_by_handle` removed the wrong value",);
+    assert_eq!(list.remove_by_handle(back,), None, "`VecList::remove_by_handle` accepted a removed handle",);
+    assert_eq!(list.back_handle().map(|id,| list[id],), Some(1), "`VecList::remove_by_handle` did not update the back",);
+
+    let mut compacting = (0..3).collect::<VecList<i32,>>();
+    let front = compacting.front_handle().expect("`VecList::front_handle` returned `None`");
+
+    compacting.set_compaction_policy(::CompactionPolicy::FreePercent(0,),);
+    compacting.remove_by_handle(front,);
+    assert_eq!(compacting.free_slots(), 0, "`VecList::remove_by_handle` ignored the compaction policy",);
+  }
+  #[test]
+  fn test_position_of() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    assert_eq!(list.position_of(back,), Some(2), "`VecList::position_of` incorrect position",);
+    list.pop_front();
+    assert_eq!(list.position_of(back,), Some(1), "`VecList::position_of` incorrect position",);
+    assert_eq!(list.position_of(front,), None, "`VecList::position_of` accepted a removed handle",);
+  }
+  #[test]
+  fn test_move_to() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    assert!(list.move_to_front(back,), "`VecList::move_to_front` rejected a valid handle",);
+    assert_eq!(list.to_vec(), vec![2, 0, 1,], "`VecList::move_to_front` did not move the value",);
+    assert!(list.move_to_back(front,), "`VecList::move_to_back` rejected a valid handle",);
+    assert_eq!(list.to_vec(), vec![2, 1, 0,], "`VecList::move_to_back` did not move the value",);
+    assert_eq!(list.front_handle(), Some(back), "`VecList::move_to_front` changed the handle",);
+    list.pop_back();
+    assert!(!list.move_to_back(front,), "`VecList::move_to_back` accepted a removed handle",);
+    assert!(!list.move_to_front(front,), "`VecList::move_to_front` accepted a removed handle",);
+  }
+  #[test]
+  fn test_exhausted_slots() {
+    let mut list = vec![1, 2,].into_iter().collect::<VecList<_,>>();
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    //Exhaust the generations of the back slot.
+    unsafe { (*list.node_mut(back.slot(),)).generation = u32::max_value() as usize - 1; }
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    list.pop_back();
+    assert_ne!(list.push_back(4,).slot(), back.slot(), "`VecList::push_back` reused an exhausted slot",);
+    assert!(!list.is_valid_handle(back,), "`VecList::is_valid_handle` accepted a removed handle",);
+  }
+  #[test]
+  fn test_swap_remove_exhausted() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+
+    //Exhaust the generations of the front slot.
+    unsafe { (*list.node_mut(front.slot(),)).generation = u32::max_value() as usize - 1; }
+    let front = list.front_handle().expect("`VecList::front_handle` returned `None`");
+
+    assert_eq!(list.swap_remove_back(0,), 1, "`VecList::swap_remove_back` removed the wrong value",);
+    assert_eq!(list.to_vec(), vec![3, 2,], "`VecList::swap_remove_back` did not move the back value",);
+    assert_ne!(list.front_handle().map(NodeId::slot,), Some(front.slot()), "`VecList::swap_remove_back` reused an exhausted slot",);
+    assert!(!list.is_valid_handle(front,), "`VecList::is_valid_handle` accepted a removed handle",);
+  }
+}
@@ -0,0 +1,163 @@
+
+use {VecList, NodeId,};
+use std::{
+  hash::{Hash, Hasher, BuildHasher, BuildHasherDefault,}, borrow::Borrow,
+  collections::{HashMap, hash_map::RandomState,},
+};
+
+/// An entry in a [`VecList`] indexed by a [`KeyIndex`].
+pub(crate) struct Entry<K, V,> {
+  /// The key of the entry.
+  pub key: K,
+  /// The value of the entry.
+  pub value: V,
+  /// The next entry whose key has the same hash.
+  pub collision: Option<NodeId>,
+}
+
+impl<K, V,> Entry<K, V,> {
+  /// Constructs a new [`Entry`] which is not in the index yet.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key of the entry.  
+  /// value --- The value of the entry.  
+  #[inline]
+  pub fn new(key: K, value: V,) -> Self { Self { key, value, collision: None, } }
+}
+
+/// A [`Hasher`] for keys which are already hashes.
+#[derive(Default,)]
+struct Hashed(u64,);
+
+impl Hasher for Hashed {
+  #[inline]
+  fn finish(&self,) -> u64 { self.0 }
+  fn write(&mut self, bytes: &[u8],) {
+    for &byte in bytes { self.0 = self.0.rotate_left(8,) ^ u64::from(byte,) }
+  }
+  #[inline]
+  fn write_u64(&mut self, hash: u64,) { self.0 = hash }
+}
+
+/// Finds the entries of a [`VecList`] by key without storing the keys a second time.
+/// 
+/// Only the hash of each key is stored, mapped to the [`NodeId`] of the entry; entries
+/// whose keys have the same hash are chained through [`Entry::collision`] and told
+/// apart by comparing the keys stored in the [`VecList`].
+pub(crate) struct KeyIndex {
+  /// Hashes the keys.
+  hasher: RandomState,
+  /// The first entry for each hash.
+  heads: HashMap<u64, NodeId, BuildHasherDefault<Hashed>>,
+}
+
+impl KeyIndex {
+  /// Constructs a new empty [`KeyIndex`] with space for `capacity` keys.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of keys to allocate space for.  
+  #[inline]
+  pub fn with_capacity(capacity: usize,) -> Self {
+    Self { hasher: RandomState::new(), heads: HashMap::with_capacity_and_hasher(capacity, Default::default(),), }
+  }
+  /// Hashes `key`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to hash.  
+  fn hash<Q: Hash + ?Sized,>(&self, key: &Q,) -> u64 {
+    let mut hasher = self.hasher.build_hasher();
+
+    key.hash(&mut hasher,); hasher.finish()
+  }
+  /// Returns the handle to the entry for `key`.
+  /// 
+  /// # Params
+  /// 
+  /// list --- The indexed entries.  
+  /// key --- The key to look up.  
+  pub fn get<K, V, Q,>(&self, list: &VecList<Entry<K, V,>,>, key: &Q,) -> Option<NodeId>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let mut next = self.heads.get(&self.hash(key,),).cloned();
+
+    while let Some(id) = next {
+      let entry = &list[id];
+
+      if entry.key.borrow() == key { return Some(id) }
+      next = entry.collision;
+    }
+
+    None
+  }
+  /// Adds the entry `id` refers to to the index.
+  /// 
+  /// # Params
+  /// 
+  /// list --- The indexed entries.  
+  /// id --- The handle to the entry.  
+  pub fn insert<K: Hash, V,>(&mut self, list: &mut VecList<Entry<K, V,>,>, id: NodeId,) {
+    let hash = self.hash(&list[id].key,);
+
+    list[id].collision = self.heads.insert(hash, id,);
+  }
+  /// Removes the entry `id` refers to from the index; it must still be in `list`.
+  /// 
+  /// # Params
+  /// 
+  /// list --- The indexed entries.  
+  /// id --- The handle to the entry.  
+  pub fn remove<K: Hash, V,>(&mut self, list: &mut VecList<Entry<K, V,>,>, id: NodeId,) {
+    let hash = self.hash(&list[id].key,);
+    let collision = list[id].collision;
+    let mut prev = *self.heads.get(&hash,).expect("`KeyIndex::remove` the entry is not indexed");
+
+    if prev == id {
+      match collision {
+        Some(next) => { self.heads.insert(hash, next,); },
+        None => { self.heads.remove(&hash,); },
+      }
+
+      return
+    }
+
+    //Unlink the entry from the chain of entries with the same hash.
+    while list[prev].collision != Some(id) {
+      prev = list[prev].collision.expect("`KeyIndex::remove` the entry is not indexed");
+    }
+    list[prev].collision = collision;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A key whose hash is the same for every value.
+  #[derive(PartialEq, Eq, Debug,)]
+  struct Colliding(i32,);
+
+  impl Hash for Colliding {
+    fn hash<H: Hasher,>(&self, _: &mut H,) {}
+  }
+
+  #[test]
+  fn test_key_index() {
+    let mut list = VecList::new();
+    let mut index = KeyIndex::with_capacity(0,);
+    let ids = (0..3).map(|key,| list.push_back(Entry::new(Colliding(key,), (),),),).collect::<Vec<_,>>();
+
+    for &id in &ids { index.insert(&mut list, id,) }
+
+    assert_eq!(index.get(&list, &Colliding(0,),), Some(ids[0]), "`KeyIndex::get` missed a chained key",);
+    assert_eq!(index.get(&list, &Colliding(3,),), None, "`KeyIndex::get` found a missing key",);
+    index.remove(&mut list, ids[1],);
+    list.remove_by_handle(ids[1],);
+    assert_eq!(index.get(&list, &Colliding(1,),), None, "`KeyIndex::remove` left the entry indexed",);
+    index.remove(&mut list, ids[2],);
+    list.remove_by_handle(ids[2],);
+    assert_eq!(index.get(&list, &Colliding(0,),), Some(ids[0]), "`KeyIndex::remove` lost the rest of the chain",);
+    assert_eq!(index.heads.len(), 1, "`KeyIndex` stored a hash twice",);
+  }
+}
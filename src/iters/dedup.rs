@@ -0,0 +1,114 @@
+
+use {VecList, Alloc, Global,};
+use std::{iter::*, ops::Drop,};
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> VecList<T, A,> {
+  /// Removes consecutive equal values, keeping the first of each run.
+  #[inline]
+  pub fn dedup(&mut self,)
+    where T: PartialEq, {
+    self.drain_duplicates();
+  }
+  /// Removes consecutive values for which `same` returns `true`, keeping the first of
+  /// each run.
+  /// 
+  /// # Params
+  /// 
+  /// same --- Called with a value and the last value kept before it.  
+  #[inline]
+  pub fn dedup_by<F,>(&mut self, same: F,)
+    where F: FnMut(&mut T, &mut T,) -> bool, {
+    self.drain_duplicates_by(same,);
+  }
+  /// Removes consecutive equal values, keeping the first of each run, and returns the
+  /// removed values as an iterator.
+  /// 
+  /// See [`VecList::drain_duplicates_by`].
+  #[inline]
+  pub fn drain_duplicates(&'t mut self,) -> DrainDuplicates<'t, T, fn(&mut T, &mut T,) -> bool, A,>
+    where T: PartialEq, {
+    fn eq<T: PartialEq,>(lhs: &mut T, rhs: &mut T,) -> bool { lhs == rhs }
+
+    self.drain_duplicates_by(eq,)
+  }
+  /// Removes consecutive values for which `same` returns `true`, keeping the first of
+  /// each run, and returns the removed values as an iterator.
+  /// 
+  /// The values are removed as the iterator is advanced; any duplicates which are not
+  /// iterated over are dropped with the iterator.
+  /// 
+  /// # Params
+  /// 
+  /// same --- Called with a value and the last value kept before it.  
+  #[inline]
+  pub fn drain_duplicates_by<F,>(&'t mut self, same: F,) -> DrainDuplicates<'t, T, F, A,>
+    where F: FnMut(&mut T, &mut T,) -> bool, {
+    DrainDuplicates { kept: self.ends.map(|(_, head, _,)| head), list: self, same, }
+  }
+}
+
+/// An iterator which removes consecutive duplicate values from a [`VecList`].
+/// 
+/// The duplicates will be removed even if they are not iterated over.
+pub struct DrainDuplicates<'t, T: 't, F, A: 't + Alloc + Clone = Global,>
+  where F: FnMut(&mut T, &mut T,) -> bool, {
+  /// The [`VecList`] being deduplicated.
+  list: &'t mut VecList<T, A,>,
+  /// The last [`Node`] which was kept.
+  kept: Option<usize>,
+  /// Returns `true` if a value duplicates the last kept value.
+  same: F,
+}
+
+impl<'t, T: 't, F, A: 't + Alloc + Clone,> Iterator for DrainDuplicates<'t, T, F, A,>
+  where F: FnMut(&mut T, &mut T,) -> bool, {
+  type Item = T;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    while let Some(kept) = self.kept {
+      let next = unsafe { (*self.list.node(kept,)).next? };
+      let same = unsafe {
+        let value = &mut *(*self.list.node_mut(next,)).value;
+
+        (self.same)(value, &mut *(*self.list.node_mut(kept,)).value,)
+      };
+
+      if same { return Some(self.list.unlink_node(next,)) }
+
+      self.kept = Some(next);
+    }
+
+    None
+  }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { (0, Some(self.list.len().saturating_sub(1,)),) }
+}
+
+impl<'t, T: 't, F, A: 't + Alloc + Clone,> FusedIterator for DrainDuplicates<'t, T, F, A,>
+  where F: FnMut(&mut T, &mut T,) -> bool, {}
+
+impl<'t, T: 't, F, A: 't + Alloc + Clone,> Drop for DrainDuplicates<'t, T, F, A,>
+  where F: FnMut(&mut T, &mut T,) -> bool, {
+  fn drop(&mut self,) {
+    self.for_each(|_| ());
+    self.list.after_removal();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_drain_duplicates() {
+    let mut list = vec![(1, 0,), (1, 1,), (2, 2,), (2, 3,), (2, 4,), (1, 5,),].into_iter().collect::<VecList<_,>>();
+    let removed = list.drain_duplicates_by(|value, kept,| value.0 == kept.0,).take(2,).collect::<Vec<_,>>();
+
+    assert_eq!(removed, vec![(1, 1,), (2, 3,),], "`VecList::drain_duplicates_by` returned the wrong values",);
+    assert_eq!(list.to_vec(), vec![(1, 0,), (2, 2,), (1, 5,),], "`DrainDuplicates` did not remove the rest on drop",);
+
+    list.push_back((1, 5,),);
+    list.dedup();
+    assert_eq!(list.len(), 3, "`VecList::dedup` kept a duplicate",);
+  }
+}
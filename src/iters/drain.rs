@@ -1,6 +1,8 @@
 
-use {VecList, NonZeroUsize,};
-use std::{iter::*, ops::Drop,};
+use {VecList, Alloc, Global,};
+use imply_option::ImplyOption;
+use super::Entries;
+use std::{iter::*, ops::Drop, fmt,};
 
 /// Creates a new [`Drain`] iterator.
 /// 
@@ -8,53 +10,112 @@ use std::{iter::*, ops::Drop,};
 /// 
 /// list --- The [`VecList`] being iterated over.  
 /// ends --- The ends of the range being iterated over.  
-pub fn drain<'t, T: 't,>(list: &'t mut VecList<T,>, ends: Option<(usize, usize,)>,) -> Drain<'t, T,> {
+pub fn drain<'t, T: 't, A: 't + Alloc + Clone,>(list: &'t mut VecList<T, A,>, ends: Option<(usize, usize,)>,) -> Drain<'t, T, A,> {
   Drain { list, ends, }
 }
 
 /// An iterator which removes values from a range in a [`VecList`].
 /// 
 /// The values in the range will be removed even if they are not iterated over.
-pub struct Drain<'t, T: 't,> {
+pub struct Drain<'t, T: 't, A: 't + Alloc + Clone = Global,> {
   /// The [`VecList`] being drained.
-  list: &'t mut VecList<T,>,
+  list: &'t mut VecList<T, A,>,
   /// The ends of the range being drained over.
   ends: Option<(usize, usize,)>,
 }
 
-impl<'t, T: 't,> Iterator for Drain<'t, T,> {
+impl<'t, T: 't, A: 't + Alloc + Clone,> Drain<'t, T, A,> {
+  /// Moves the remaining values into `target` so they start at index `at` in their
+  /// current order.
+  /// 
+  /// Returns the number of values moved.
+  /// 
+  /// # Params
+  /// 
+  /// target --- The [`VecList`] to move the values into.  
+  /// at --- The index in `target` to insert the values at.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `at > target.len()`.
+  pub fn splice_into(mut self, target: &mut VecList<T, A,>, at: usize,) -> usize {
+    assert!(at <= target.len(), "`Drain::splice_into` index out of range",);
+
+    //The `Node` to insert the values before.
+    let next = if at == target.len() { None } else { Some(target.ptr(at,)) };
+    let mut count = 0;
+
+    for value in &mut self {
+      target.link_before(next, value,);
+      count += 1;
+    }
+
+    count
+  }
+  /// Removes up to `n` values from the front of the range in a single step.
+  /// 
+  /// Returns the number of values removed.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to remove.  
+  fn skip_front(&mut self, n: usize,) -> usize {
+    match self.ends {
+      Some((front, back,)) if n > 0 => unsafe {
+        //Walk forwards over the skipped values.
+        let (mut end, mut count,) = (front, 1,);
+        while count < n && end != back { end = (*self.list.node(end,)).next(); count += 1 }
+
+        self.ends = (end != back).then_do(|| ((*self.list.node(end,)).next(), back,),);
+        self.list.unlink_segment(front, end, count,);
+        self.list.free_segment(front, end, count,);
+
+        count
+      },
+      _ => 0,
+    }
+  }
+  /// Removes up to `n` values from the back of the range in a single step.
+  /// 
+  /// Returns the number of values removed.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to remove.  
+  fn skip_back(&mut self, n: usize,) -> usize {
+    match self.ends {
+      Some((front, back,)) if n > 0 => unsafe {
+        //Walk backwards over the skipped values.
+        let (mut start, mut count,) = (back, 1,);
+        while count < n && start != front { start = (*self.list.node(start,)).prev(); count += 1 }
+
+        self.ends = (start != front).then_do(|| (front, (*self.list.node(start,)).prev(),),);
+        self.list.unlink_segment(start, back, count,);
+        self.list.free_segment(start, back, count,);
+
+        count
+      },
+      _ => 0,
+    }
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Iterator for Drain<'t, T, A,> {
   type Item = T;
 
   #[inline]
   fn next(&mut self,) -> Option<Self::Item> {
-    self.ends.map(|(front, back,)| { unsafe {
-      use imply_option::ImplyOption;
-      use std::hint;
-
-      //The `Node` of the `front` pointer.
-      let front_node = &*self.list.node(front,);
-
+    self.ends.map(|(front, back,)| {
       //Update the ends of the range being iterated over.
-      self.ends = (front != back).then_do(|| (front_node.next(), back,),);
-      //Update the ends of the `VecList`.
-      match self.list.ends {
-        //Iteration would not be happening if the `VecList` was empty.
-        None => hint::unreachable_unchecked(),
-        //If there is only a single node, the `VecList` is now empty.
-        Some((len, head, tail,)) => {
-          let nlen = NonZeroUsize::new_unchecked(len.get() - 1,);
-          
-          if head == tail { self.list.ends = None }
-          //If the head has been drained, update the head pointer.
-          else if head == front { self.list.ends = Some((nlen, front_node.next(), tail,)) }
-          //If the tail has been drained, update the tail pointer.
-          else if tail == front { self.list.ends = Some((nlen, head, front_node.prev(),)) }
-        },
-      }
-
-      //Deallocate the `Node`.
-      self.list.dealloc_node(front,)
-    }})
+      self.ends = (front != back).then_do(|| (unsafe { (*self.list.node(front,)).next() }, back,),);
+
+      self.list.unlink_node(front,)
+    })
+  }
+  #[inline]
+  fn nth(&mut self, n: usize,) -> Option<Self::Item> {
+    self.skip_front(n,);
+    self.next()
   }
   fn size_hint(&self) -> (usize, Option<usize>,) {
     //If there are ends there is at least one more value.
@@ -64,43 +125,34 @@ impl<'t, T: 't,> Iterator for Drain<'t, T,> {
   }
 }
 
-impl<'t, T: 't,> DoubleEndedIterator for Drain<'t, T,> {
+impl<'t, T: 't, A: 't + Alloc + Clone,> DoubleEndedIterator for Drain<'t, T, A,> {
   #[inline]
   fn next_back(&mut self,) -> Option<Self::Item> {
-    self.ends.map(|(front, back,)| { unsafe {
-      use imply_option::ImplyOption;
-      use std::hint;
+    self.ends.map(|(front, back,)| {
+      //Update the ends of the range being iterated over.
+      self.ends = (front != back).then_do(|| (front, unsafe { (*self.list.node(back,)).prev() },),);
 
-      //The `Node` of the `back` pointer.
-      let back_node = &*self.list.node(back,);
+      self.list.unlink_node(back,)
+    })
+  }
+  #[inline]
+  fn nth_back(&mut self, n: usize,) -> Option<Self::Item> {
+    self.skip_back(n,);
+    self.next_back()
+  }
+}
 
-      //Update the ends of the range being iterated over.
-      self.ends = (front != back).then_do(|| (front, back_node.prev(),),);
-      //Update the ends of the `VecList`.
-      match self.list.ends {
-        //Iteration would not be happening if the `VecList` was empty.
-        None => hint::unreachable_unchecked(),
-        //If there is only a single node, the `VecList` is now empty.
-        Some((len, head, tail,)) => {
-          let nlen = NonZeroUsize::new_unchecked(len.get() - 1,);
-          
-          if head == tail { self.list.ends = None }
-          //If the head has been drained, update the head pointer.
-          else if head == back { self.list.ends = Some((nlen, back_node.next(), tail,)) }
-          //If the tail has been drained, update the tail pointer.
-          else if tail == back { self.list.ends = Some((nlen, head, back_node.prev(),)) }
-        },
-      }
-
-      //Deallocate the `Node`.
-      self.list.dealloc_node(back,)
-    }})
+impl<'t, T: 't + fmt::Debug, A: 't + Alloc + Clone,> fmt::Debug for Drain<'t, T, A,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_tuple("Drain",).field(&Entries { list: self.list, ends: self.ends, },).finish()
   }
 }
 
-impl<'t, T: 't,> Drop for Drain<'t, T,> {
-  #[inline]
-  fn drop(&mut self,) { self.for_each(|_| ()) }
+impl<'t, T: 't, A: 't + Alloc + Clone,> Drop for Drain<'t, T, A,> {
+  fn drop(&mut self,) {
+    self.for_each(|_| ());
+    self.list.after_removal();
+  }
 }
 
 #[cfg(test)]
@@ -129,4 +181,17 @@ mod tests {
     test_drop.assert_drop(ids[3]);
     test_drop.assert_no_drop(ids[4]);
   }
+  #[test]
+  fn test_splice_into() {
+    let mut list = (0..6).collect::<VecList<i32,>>();
+    let mut target = vec![10, 11,].into_iter().collect::<VecList<i32,>>();
+    let mut drain = list.drain(1..5,);
+
+    assert_eq!(drain.next(), Some(1), "`Drain::next` returned the wrong value",);
+    assert_eq!(drain.splice_into(&mut target, 1,), 3, "`Drain::splice_into` incorrect count",);
+    assert_eq!(target, [10, 2, 3, 4, 11,], "`Drain::splice_into` inserted incorrectly",);
+    assert_eq!(list, [0, 5,], "`Drain::splice_into` left values in the source",);
+    assert_eq!(list.drain(..,).splice_into(&mut target, 5,), 2, "`Drain::splice_into` incorrect count",);
+    assert_eq!(target, [10, 2, 3, 4, 11, 0, 5,], "`Drain::splice_into` did not append at the end",);
+  }
 }
@@ -0,0 +1,185 @@
+
+use {VecList, Alloc, Global,};
+use std::{iter::*, ops::{RangeBounds, Drop,},};
+
+/// Tells [`VecList::drain_filter_while`] whether to keep scanning.
+#[derive(PartialEq, Eq, Clone, Copy, Debug,)]
+pub enum ControlFlow<B, C = (),> {
+  /// Keep scanning with the passed value.
+  Continue(C),
+  /// Stop scanning with the passed value.
+  Break(B),
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> VecList<T, A,> {
+  /// Removes the values for which `pred` returns `true` and returns them as an iterator.
+  /// 
+  /// See [`VecList::extract_if`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- Returns `true` for the values to remove.  
+  #[inline]
+  pub fn drain_filter<P,>(&'t mut self, pred: P,) -> ExtractIf<'t, T, P, A,>
+    where P: FnMut(&mut T,) -> bool, {
+    self.extract_if(.., pred,)
+  }
+  /// Removes the values for which `pred` returns `Continue(true)` and returns them as an
+  /// iterator, stopping as soon as `pred` returns `Break`.
+  /// 
+  /// Values are visited in list order as the iterator is advanced and every value from
+  /// the one `pred` breaks on is kept without being visited, so a scan over a mostly
+  /// sorted [`VecList`] can end early.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- Returns whether to remove each value or `Break` to stop.  
+  #[inline]
+  pub fn drain_filter_while<P,>(&'t mut self, pred: P,) -> ExtractWhile<'t, T, P, A,>
+    where P: FnMut(&mut T,) -> ControlFlow<(), bool>, {
+    ExtractWhile { ends: self.range_ends(..,), list: self, pred, }
+  }
+  /// Removes the values in `range` for which `pred` returns `true` and returns them as
+  /// an iterator.
+  /// 
+  /// Only the values in `range` are passed to `pred` and they are visited in list
+  /// order as the iterator is advanced. Values which have not been visited when the
+  /// iterator is dropped are kept.
+  /// 
+  /// # Params
+  /// 
+  /// range --- The range of indexes to filter.  
+  /// pred --- Returns `true` for the values to remove.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `range.end > self.len()`.
+  #[inline]
+  pub fn extract_if<R, P,>(&'t mut self, range: R, pred: P,) -> ExtractIf<'t, T, P, A,>
+    where R: RangeBounds<usize>, P: FnMut(&mut T,) -> bool, {
+    ExtractIf { ends: self.range_ends(range,), list: self, pred, }
+  }
+}
+
+/// An iterator which removes the values matching a predicate from a range in a
+/// [`VecList`].
+pub struct ExtractIf<'t, T: 't, P, A: 't + Alloc + Clone = Global,>
+  where P: FnMut(&mut T,) -> bool, {
+  /// The [`VecList`] being filtered.
+  list: &'t mut VecList<T, A,>,
+  /// The ends of the range left to filter.
+  ends: Option<(usize, usize,)>,
+  /// Returns `true` for the values to remove.
+  pred: P,
+}
+
+/// An iterator which removes the values matching a predicate from a [`VecList`] until
+/// the predicate breaks.
+pub struct ExtractWhile<'t, T: 't, P, A: 't + Alloc + Clone = Global,>
+  where P: FnMut(&mut T,) -> ControlFlow<(), bool>, {
+  /// The [`VecList`] being filtered.
+  list: &'t mut VecList<T, A,>,
+  /// The ends of the range left to filter.
+  ends: Option<(usize, usize,)>,
+  /// Returns whether to remove each value or `Break` to stop.
+  pred: P,
+}
+
+/// Removes the next value in `ends` which `pred` selects.
+/// 
+/// # Params
+/// 
+/// list --- The [`VecList`] being filtered.  
+/// ends --- The ends of the range left to filter.  
+/// pred --- Returns whether to remove each value or `Break` to stop.  
+fn extract_next<T, P, A: Alloc + Clone,>(list: &mut VecList<T, A,>, ends: &mut Option<(usize, usize,)>, mut pred: P,) -> Option<T>
+  where P: FnMut(&mut T,) -> ControlFlow<(), bool>, {
+  while let Some((front, back,)) = *ends {
+    let flow = unsafe {
+      let node = &mut *list.node_mut(front,);
+
+      //Update the ends of the range being filtered.
+      *ends = if front == back { None } else { Some((node.next(), back,)) };
+      pred(&mut node.value,)
+    };
+
+    match flow {
+      ControlFlow::Continue(true) => return Some(list.unlink_node(front,)),
+      ControlFlow::Continue(false) => (),
+      //Stop before the value which broke.
+      ControlFlow::Break(()) => { *ends = None; break },
+    }
+  }
+
+  None
+}
+
+impl<'t, T: 't, P, A: 't + Alloc + Clone,> Iterator for ExtractIf<'t, T, P, A,>
+  where P: FnMut(&mut T,) -> bool, {
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    let pred = &mut self.pred;
+
+    extract_next(self.list, &mut self.ends, |value,| ControlFlow::Continue(pred(value,),),)
+  }
+}
+
+impl<'t, T: 't, P, A: 't + Alloc + Clone,> Iterator for ExtractWhile<'t, T, P, A,>
+  where P: FnMut(&mut T,) -> ControlFlow<(), bool>, {
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> { extract_next(self.list, &mut self.ends, &mut self.pred,) }
+}
+
+impl<'t, T: 't, P, A: 't + Alloc + Clone,> FusedIterator for ExtractIf<'t, T, P, A,>
+  where P: FnMut(&mut T,) -> bool, {}
+
+impl<'t, T: 't, P, A: 't + Alloc + Clone,> FusedIterator for ExtractWhile<'t, T, P, A,>
+  where P: FnMut(&mut T,) -> ControlFlow<(), bool>, {}
+
+impl<'t, T: 't, P, A: 't + Alloc + Clone,> Drop for ExtractIf<'t, T, P, A,>
+  where P: FnMut(&mut T,) -> bool, {
+  #[inline]
+  fn drop(&mut self,) { self.list.after_removal() }
+}
+
+impl<'t, T: 't, P, A: 't + Alloc + Clone,> Drop for ExtractWhile<'t, T, P, A,>
+  where P: FnMut(&mut T,) -> ControlFlow<(), bool>, {
+  #[inline]
+  fn drop(&mut self,) { self.list.after_removal() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_extract_if() {
+    let mut list = (0..10).collect::<VecList<i32,>>();
+
+    assert_eq!(list.extract_if(2..8, |value,| *value % 2 == 0,).collect::<Vec<_,>>(), vec![2, 4, 6,],
+      "`VecList::extract_if` removed the wrong values",);
+    assert_eq!(list, [0, 1, 3, 5, 7, 8, 9,], "`VecList::extract_if` filtered outside the range",);
+
+    assert_eq!(list.drain_filter(|value,| *value > 6,).next(), Some(7), "`VecList::drain_filter` removed the wrong value",);
+    assert_eq!(list, [0, 1, 3, 5, 8, 9,], "`ExtractIf` removed unvisited values",);
+  }
+  #[test]
+  fn test_drain_filter_while() {
+    let mut list = vec![0, 1, 3, 5, 8, 9,].into_iter().collect::<VecList<i32,>>();
+    let mut visited = 0;
+    let expired = list.drain_filter_while(|value,| {
+      visited += 1;
+      if *value < 5 { ControlFlow::Continue(*value % 3 != 0) } else { ControlFlow::Break(()) }
+    },).collect::<Vec<_,>>();
+
+    assert_eq!(expired, vec![1,], "`VecList::drain_filter_while` removed the wrong values",);
+    assert_eq!(visited, 4, "`VecList::drain_filter_while` did not stop at the break",);
+    assert_eq!(list, [0, 3, 5, 8, 9,], "`VecList::drain_filter_while` removed values after the break",);
+    assert_eq!(list.drain_filter_while(|_,| ControlFlow::Continue(true),).count(), 5, "`VecList::drain_filter_while` stopped without a break",);
+    assert!(list.is_empty(), "`VecList::drain_filter_while` kept values",);
+  }
+}
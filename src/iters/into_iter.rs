@@ -0,0 +1,69 @@
+
+use {VecList, Alloc, Global,};
+use std::iter::*;
+
+/// An iterator which moves the values out of a [`VecList`] in order.
+/// 
+/// Any values which are not iterated over are dropped with the iterator.
+pub struct IntoIter<T, A: Alloc + Clone = Global,> {
+  /// The [`VecList`] being consumed.
+  list: VecList<T, A,>,
+}
+
+impl<T, A: Alloc + Clone,> IntoIterator for VecList<T, A,> {
+  type Item = T;
+  type IntoIter = IntoIter<T, A,>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { IntoIter { list: self, } }
+}
+
+impl<T, A: Alloc + Clone,> Iterator for IntoIter<T, A,> {
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> { self.list.pop_front() }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { (self.list.len(), Some(self.list.len()),) }
+}
+
+impl<T, A: Alloc + Clone,> DoubleEndedIterator for IntoIter<T, A,> {
+  #[inline]
+  fn next_back(&mut self,) -> Option<Self::Item> { self.list.pop_back() }
+}
+
+impl<T, A: Alloc + Clone,> ExactSizeIterator for IntoIter<T, A,> {}
+
+impl<T, A: Alloc + Clone,> FusedIterator for IntoIter<T, A,> {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use testdrop::*;
+
+  #[test]
+  fn test_into_iter() {
+    let test_drop = TestDrop::new();
+    let mut list = VecList::<Item>::new();
+    let mut ids = Vec::new();
+
+    for _ in 0..4 {
+      let (id, item,) = test_drop.new_item();
+
+      list.push_back(item,);
+      ids.push(id,);
+    }
+
+    let mut iter = list.into_iter();
+
+    assert_eq!(iter.len(), 4, "`IntoIter` incorrect length",);
+    assert_eq!(iter.next().map(|item| item.id()), Some(ids[0]), "`IntoIter::next` returned the wrong value",);
+    assert_eq!(iter.next_back().map(|item| item.id()), Some(ids[3]), "`IntoIter::next_back` returned the wrong value",);
+    test_drop.assert_no_drop(ids[1],);
+    test_drop.assert_no_drop(ids[2],);
+
+    drop(iter,);
+    test_drop.assert_drop(ids[1],);
+    test_drop.assert_drop(ids[2],);
+  }
+}
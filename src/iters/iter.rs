@@ -0,0 +1,217 @@
+
+use {VecList, Alloc, Global,};
+use std::{iter::*, marker::PhantomData, fmt,};
+
+/// An iterator over references to the values in a [`VecList`].
+pub struct Iter<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] being iterated over.
+  list: &'t VecList<T, A,>,
+  /// The ends of the range being iterated over.
+  ends: Option<(usize, usize,)>,
+  /// The number of values left in the range.
+  len: usize,
+}
+
+/// An iterator over mutable references to the values in a [`VecList`].
+pub struct IterMut<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] being iterated over.
+  list: *mut VecList<T, A,>,
+  /// The ends of the range being iterated over.
+  ends: Option<(usize, usize,)>,
+  /// The number of values left in the range.
+  len: usize,
+  /// The mutable borrow of the [`VecList`].
+  _marker: PhantomData<&'t mut VecList<T, A,>>,
+}
+
+/// Formats the values in a range of a [`VecList`] as a list.
+pub(crate) struct Entries<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] containing the range.
+  pub list: &'t VecList<T, A,>,
+  /// The ends of the range.
+  pub ends: Option<(usize, usize,)>,
+}
+
+impl<'t, T: 't + fmt::Debug, A: 't + Alloc + Clone,> fmt::Debug for Entries<'t, T, A,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    let mut list = fmt.debug_list();
+
+    if let Some((front, back,)) = self.ends {
+      let mut ptr = front;
+
+      loop {
+        let node = unsafe { &*self.list.node(ptr,) };
+
+        list.entry(&*node.value,);
+        if ptr == back { break }
+        ptr = node.next();
+      }
+    }
+
+    list.finish()
+  }
+}
+
+//An `IterMut` behaves like a `&mut VecList`.
+unsafe impl<'t, T: 't + Send, A: 't + Alloc + Clone,> Send for IterMut<'t, T, A,> {}
+unsafe impl<'t, T: 't + Sync, A: 't + Alloc + Clone,> Sync for IterMut<'t, T, A,> {}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> VecList<T, A,> {
+  /// Returns an iterator over references to the values in the [`VecList`].
+  #[inline]
+  pub fn iter(&'t self,) -> Iter<'t, T, A,> {
+    Iter { list: self, ends: self.ends.map(|(_, head, tail,)| (head, tail,)), len: self.len(), }
+  }
+  /// Returns an iterator over mutable references to the values in the [`VecList`].
+  #[inline]
+  pub fn iter_mut(&'t mut self,) -> IterMut<'t, T, A,> {
+    IterMut {
+      ends: self.ends.map(|(_, head, tail,)| (head, tail,)),
+      len: self.len(),
+      list: self,
+      _marker: PhantomData,
+    }
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Iterator for Iter<'t, T, A,> {
+  type Item = &'t T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    self.ends.map(|(front, back,)| unsafe {
+      let node = &*self.list.node(front,);
+
+      self.ends = if front == back { None } else { Some((node.next(), back,)) };
+      self.len -= 1;
+
+      &*node.value
+    })
+  }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { (self.len, Some(self.len),) }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> DoubleEndedIterator for Iter<'t, T, A,> {
+  #[inline]
+  fn next_back(&mut self,) -> Option<Self::Item> {
+    self.ends.map(|(front, back,)| unsafe {
+      let node = &*self.list.node(back,);
+
+      self.ends = if front == back { None } else { Some((front, node.prev(),)) };
+      self.len -= 1;
+
+      &*node.value
+    })
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> ExactSizeIterator for Iter<'t, T, A,> {}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> FusedIterator for Iter<'t, T, A,> {}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Clone for Iter<'t, T, A,> {
+  #[inline]
+  fn clone(&self,) -> Self { Iter { list: self.list, ends: self.ends, len: self.len, } }
+}
+
+impl<'t, T: 't + fmt::Debug, A: 't + Alloc + Clone,> fmt::Debug for Iter<'t, T, A,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_tuple("Iter",).field(&Entries { list: self.list, ends: self.ends, },).finish()
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> IterMut<'t, T, A,> {
+  /// Constructs an [`IterMut`] over the `len` values from `ends.0` to `ends.1`.
+  /// 
+  /// The values in the range must not be reachable through any other reference while
+  /// the [`IterMut`] exists.
+  /// 
+  /// # Params
+  /// 
+  /// list --- The [`VecList`] to iterate over.  
+  /// ends --- The ends of the range to iterate over.  
+  /// len --- The number of values in the range.  
+  pub(crate) unsafe fn from_range(list: *mut VecList<T, A,>, ends: Option<(usize, usize,)>, len: usize,) -> Self {
+    IterMut { list, ends, len, _marker: PhantomData, }
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Iterator for IterMut<'t, T, A,> {
+  type Item = &'t mut T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    self.ends.map(|(front, back,)| unsafe {
+      let node = &mut *(*self.list).node_mut(front,);
+
+      self.ends = if front == back { None } else { Some((node.next(), back,)) };
+      self.len -= 1;
+
+      &mut *node.value
+    })
+  }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { (self.len, Some(self.len),) }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> DoubleEndedIterator for IterMut<'t, T, A,> {
+  #[inline]
+  fn next_back(&mut self,) -> Option<Self::Item> {
+    self.ends.map(|(front, back,)| unsafe {
+      let node = &mut *(*self.list).node_mut(back,);
+
+      self.ends = if front == back { None } else { Some((front, node.prev(),)) };
+      self.len -= 1;
+
+      &mut *node.value
+    })
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> ExactSizeIterator for IterMut<'t, T, A,> {}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> FusedIterator for IterMut<'t, T, A,> {}
+
+impl<'t, T: 't + fmt::Debug, A: 't + Alloc + Clone,> fmt::Debug for IterMut<'t, T, A,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    let list = unsafe { &*self.list };
+
+    fmt.debug_tuple("IterMut",).field(&Entries { list, ends: self.ends, },).finish()
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> IntoIterator for &'t VecList<T, A,> {
+  type Item = &'t T;
+  type IntoIter = Iter<'t, T, A,>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> IntoIterator for &'t mut VecList<T, A,> {
+  type Item = &'t mut T;
+  type IntoIter = IterMut<'t, T, A,>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter_mut() }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_iter() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+
+    for value in &mut list { *value += 1 }
+
+    let mut iter = (&list).into_iter();
+
+    assert_eq!(iter.len(), 3, "`Iter` incorrect length",);
+    assert_eq!(format!("{:?}", iter,), "Iter([1, 2, 3])", "`Iter` debugged incorrectly",);
+    assert_eq!(iter.next_back(), Some(&3), "`Iter::next_back` returned the wrong value",);
+    assert_eq!(iter.collect::<Vec<_,>>(), vec![&1, &2,], "`Iter` returned the wrong values",);
+  }
+}
@@ -1,4 +1,10 @@
 
+mod iter;
 mod drain;
+mod snapshot;
+mod into_iter;
+mod set_ops;
+mod dedup;
+mod extract_if;
 
-pub use self::drain::*;
+pub use self::{iter::*, drain::*, snapshot::*, into_iter::*, set_ops::*, dedup::*, extract_if::*,};
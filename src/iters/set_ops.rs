@@ -0,0 +1,193 @@
+
+use {VecList, Alloc, Global,};
+use super::Iter;
+use std::{iter::*, cmp::Ordering,};
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> VecList<T, A,> {
+  /// Returns an iterator over the values in either sorted [`VecList`] in ascending order.
+  /// 
+  /// Equal values are matched pairwise and yielded once from this [`VecList`]. If either
+  /// [`VecList`] is not sorted the values yielded are unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The other sorted [`VecList`].  
+  #[inline]
+  pub fn union(&'t self, other: &'t Self,) -> Union<'t, T, A,> {
+    Union { pair: SortedPair::new(self, other,), }
+  }
+  /// Returns an iterator over the values in both sorted [`VecList`]s in ascending order.
+  /// 
+  /// Equal values are matched pairwise and yielded from this [`VecList`]. If either
+  /// [`VecList`] is not sorted the values yielded are unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The other sorted [`VecList`].  
+  #[inline]
+  pub fn intersection(&'t self, other: &'t Self,) -> Intersection<'t, T, A,> {
+    Intersection { pair: SortedPair::new(self, other,), }
+  }
+  /// Returns an iterator over the values in this sorted [`VecList`] which are not in
+  /// `other` in ascending order.
+  /// 
+  /// Equal values are matched pairwise. If either [`VecList`] is not sorted the values
+  /// yielded are unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The other sorted [`VecList`].  
+  #[inline]
+  pub fn difference(&'t self, other: &'t Self,) -> Difference<'t, T, A,> {
+    Difference { pair: SortedPair::new(self, other,), }
+  }
+  /// Returns an iterator over the values in exactly one of the sorted [`VecList`]s in
+  /// ascending order.
+  /// 
+  /// Equal values are matched pairwise. If either [`VecList`] is not sorted the values
+  /// yielded are unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The other sorted [`VecList`].  
+  #[inline]
+  pub fn symmetric_difference(&'t self, other: &'t Self,) -> SymmetricDifference<'t, T, A,> {
+    SymmetricDifference { pair: SortedPair::new(self, other,), }
+  }
+}
+
+/// Walks two sorted [`VecList`]s in step.
+struct SortedPair<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The values of the left [`VecList`].
+  lhs: Peekable<Iter<'t, T, A,>>,
+  /// The values of the right [`VecList`].
+  rhs: Peekable<Iter<'t, T, A,>>,
+}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> SortedPair<'t, T, A,> {
+  /// Starts walking `lhs` and `rhs`.
+  #[inline]
+  fn new(lhs: &'t VecList<T, A,>, rhs: &'t VecList<T, A,>,) -> Self {
+    Self { lhs: lhs.iter().peekable(), rhs: rhs.iter().peekable(), }
+  }
+  /// Takes the lesser of the next values or both if they are equal.
+  fn next(&mut self,) -> (Option<&'t T>, Option<&'t T>,) {
+    let order = match (self.lhs.peek(), self.rhs.peek(),) {
+      (Some(lhs), Some(rhs),) => lhs.cmp(rhs,),
+      (Some(_), None,) => Ordering::Less,
+      (None, Some(_),) => Ordering::Greater,
+      (None, None,) => return (None, None,),
+    };
+
+    match order {
+      Ordering::Less => (self.lhs.next(), None,),
+      Ordering::Greater => (None, self.rhs.next(),),
+      Ordering::Equal => (self.lhs.next(), self.rhs.next(),),
+    }
+  }
+  /// Returns `true` if the left [`VecList`] is exhausted.
+  #[inline]
+  fn lhs_done(&mut self,) -> bool { self.lhs.peek().is_none() }
+  /// Returns `true` if the right [`VecList`] is exhausted.
+  #[inline]
+  fn rhs_done(&mut self,) -> bool { self.rhs.peek().is_none() }
+}
+
+/// An iterator over the union of two sorted [`VecList`]s.
+pub struct Union<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The lists being walked.
+  pair: SortedPair<'t, T, A,>,
+}
+
+/// An iterator over the intersection of two sorted [`VecList`]s.
+pub struct Intersection<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The lists being walked.
+  pair: SortedPair<'t, T, A,>,
+}
+
+/// An iterator over the difference of two sorted [`VecList`]s.
+pub struct Difference<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The lists being walked.
+  pair: SortedPair<'t, T, A,>,
+}
+
+/// An iterator over the symmetric difference of two sorted [`VecList`]s.
+pub struct SymmetricDifference<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The lists being walked.
+  pair: SortedPair<'t, T, A,>,
+}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> Iterator for Union<'t, T, A,> {
+  type Item = &'t T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> {
+    match self.pair.next() {
+      (Some(value), _,) | (None, Some(value),) => Some(value),
+      (None, None,) => None,
+    }
+  }
+}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> Iterator for Intersection<'t, T, A,> {
+  type Item = &'t T;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    while !self.pair.lhs_done() && !self.pair.rhs_done() {
+      if let (Some(value), Some(_),) = self.pair.next() { return Some(value) }
+    }
+
+    None
+  }
+}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> Iterator for Difference<'t, T, A,> {
+  type Item = &'t T;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    while !self.pair.lhs_done() {
+      if let (Some(value), None,) = self.pair.next() { return Some(value) }
+    }
+
+    None
+  }
+}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> Iterator for SymmetricDifference<'t, T, A,> {
+  type Item = &'t T;
+
+  fn next(&mut self,) -> Option<Self::Item> {
+    loop {
+      match self.pair.next() {
+        (Some(value), None,) | (None, Some(value),) => return Some(value),
+        (Some(_), Some(_),) => (),
+        (None, None,) => return None,
+      }
+    }
+  }
+}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> FusedIterator for Union<'t, T, A,> {}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> FusedIterator for Intersection<'t, T, A,> {}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> FusedIterator for Difference<'t, T, A,> {}
+
+impl<'t, T: 't + Ord, A: 't + Alloc + Clone,> FusedIterator for SymmetricDifference<'t, T, A,> {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_set_ops() {
+    let lhs = vec![1, 2, 2, 4, 6,].into_iter().collect::<VecList<i32,>>();
+    let rhs = vec![2, 3, 4, 7,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(lhs.union(&rhs,).cloned().collect::<Vec<_,>>(), vec![1, 2, 2, 3, 4, 6, 7,], "`VecList::union` incorrect result",);
+    assert_eq!(lhs.intersection(&rhs,).cloned().collect::<Vec<_,>>(), vec![2, 4,], "`VecList::intersection` incorrect result",);
+    assert_eq!(lhs.difference(&rhs,).cloned().collect::<Vec<_,>>(), vec![1, 2, 6,], "`VecList::difference` incorrect result",);
+    assert_eq!(lhs.symmetric_difference(&rhs,).cloned().collect::<Vec<_,>>(), vec![1, 2, 3, 6, 7,],
+      "`VecList::symmetric_difference` incorrect result",);
+  }
+}
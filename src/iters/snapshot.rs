@@ -0,0 +1,53 @@
+
+use {VecList, Alloc,};
+use std::{iter::*, vec,};
+
+impl<T: Clone, A: Alloc + Clone,> VecList<T, A,> {
+  /// Returns an iterator over a snapshot of the values in the [`VecList`].
+  /// 
+  /// The values are cloned up front so the snapshot is unaffected by the [`VecList`]
+  /// being mutated or dropped while it is iterated over.
+  #[inline]
+  pub fn iter_snapshot(&self,) -> Snapshot<T,> { Snapshot { values: self.to_vec().into_iter(), } }
+}
+
+/// An iterator over a snapshot of the values in a [`VecList`].
+pub struct Snapshot<T,> {
+  /// The captured values.
+  values: vec::IntoIter<T>,
+}
+
+impl<T,> Iterator for Snapshot<T,> {
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self,) -> Option<Self::Item> { self.values.next() }
+  #[inline]
+  fn size_hint(&self,) -> (usize, Option<usize>,) { self.values.size_hint() }
+}
+
+impl<T,> DoubleEndedIterator for Snapshot<T,> {
+  #[inline]
+  fn next_back(&mut self,) -> Option<Self::Item> { self.values.next_back() }
+}
+
+impl<T,> ExactSizeIterator for Snapshot<T,> {}
+
+impl<T,> FusedIterator for Snapshot<T,> {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_snapshot() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let snapshot = list.iter_snapshot();
+
+    list.push_back(3,);
+    list.pop_front();
+    drop(list,);
+
+    assert_eq!(snapshot.collect::<Vec<_,>>(), vec![0, 1, 2,], "`Snapshot` was affected by mutation",);
+  }
+}
@@ -0,0 +1,212 @@
+
+use {VecList, Node, Alloc,};
+use std::mem;
+
+/// Whether a slot in the buffer of a [`VecList`] holds a value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub enum SlotState {
+  /// The slot holds a value which is linked into the list.
+  Occupied,
+  /// The slot is on the stack of empty [`Node`]s.
+  Free,
+}
+
+/// A description of a single slot in the buffer of a [`VecList`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub struct SlotLayout {
+  /// Whether the slot holds a value.
+  pub state: SlotState,
+  /// The previous slot in the list; always `None` for free slots.
+  pub prev: Option<usize>,
+  /// The next slot in the list or the next slot in the stack of free slots.
+  pub next: Option<usize>,
+  /// The number of times the slot has been allocated or deallocated.
+  pub generation: usize,
+}
+
+/// A description of the internal layout of a [`VecList`].
+#[derive(Clone, PartialEq, Eq, Debug,)]
+pub struct ListLayout {
+  /// Every initialised slot in the buffer in buffer order.
+  pub slots: Vec<SlotLayout>,
+  /// The capacity of the buffer.
+  pub capacity: usize,
+  /// The number of values in the list.
+  pub len: usize,
+  /// The slot at the front of the list.
+  pub head: Option<usize>,
+  /// The slot at the back of the list.
+  pub tail: Option<usize>,
+  /// The slot on top of the stack of free slots.
+  pub free_head: Option<usize>,
+  /// The number of free slots.
+  pub free_len: usize,
+}
+
+/// A summary of the memory used by a [`VecList`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug,)]
+pub struct MemoryUsage {
+  /// The number of bytes allocated for the buffer.
+  pub bytes_allocated: usize,
+  /// The number of bytes taken up by the values in the list.
+  pub bytes_live: usize,
+  /// The number of bytes each [`Node`] uses on top of its value.
+  pub node_overhead: usize,
+  /// The number of empty slots waiting to be reused.
+  pub free_slots: usize,
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Returns a description of the internal layout of the [`VecList`].
+  /// 
+  /// This is intended for tests and debugging tools which need to inspect how the
+  /// [`Node`]s are arranged in the buffer.
+  pub fn dump_layout(&self,) -> ListLayout {
+    let slots = (0..self.node_count).map(|ptr| {
+      let node = unsafe { &*self.node(ptr,) };
+
+      //Allocated slots have an even generation.
+      if node.generation % 2 == 0 {
+        SlotLayout { state: SlotState::Occupied, prev: node.prev, next: node.next, generation: node.generation, }
+      } else {
+        SlotLayout { state: SlotState::Free, prev: None, next: node.next, generation: node.generation, }
+      }
+    }).collect();
+
+    ListLayout {
+      slots,
+      capacity: self.capacity(),
+      len: self.len(),
+      head: self.ends.map(|(_, head, _,)| head),
+      tail: self.ends.map(|(_, _, tail,)| tail),
+      free_head: self.empty.map(|(_, empty,)| empty),
+      free_len: self.empty.map_or(0, |(len, _,)| len.get(),),
+    }
+  }
+  /// Returns a summary of the memory used by the [`VecList`].
+  pub fn memory_usage(&self,) -> MemoryUsage {
+    let (node, value,) = (mem::size_of::<Node<T,>>(), mem::size_of::<T>(),);
+
+    MemoryUsage {
+      bytes_allocated: self.capacity().saturating_mul(node,),
+      bytes_live: self.len() * value,
+      node_overhead: node - value,
+      free_slots: self.free_slots(),
+    }
+  }
+  /// Returns the number of empty slots waiting to be reused.
+  #[inline]
+  pub fn free_slots(&self,) -> usize { self.empty.map_or(0, |(len, _,)| len.get(),) }
+  /// Returns the number of slots which have ever held a value.
+  /// 
+  /// The buffer cannot be shrunk below this without relocating values, see
+  /// [`VecList::shrink_to_fit`].
+  #[inline]
+  pub fn slot_high_water_mark(&self,) -> usize { self.node_count }
+  /// Returns `true` if the values are laid out in list order at the start of the buffer
+  /// with no empty slots between them.
+  /// 
+  /// This is the state of a freshly collected or compacted [`VecList`].
+  pub fn is_contiguous(&self,) -> bool {
+    let (len, head, tail,) = match self.ends {
+      Some(ends) => ends,
+      None => return true,
+    };
+
+    head == 0 && tail == len.get() - 1 && (0..tail).all(|ptr| unsafe { (*self.node(ptr,)).next } == Some(ptr + 1),)
+  }
+  /// Returns the fraction of neighbouring values which are not in neighbouring slots.
+  /// 
+  /// `0.0` means the values are laid out contiguously in list order and `1.0` means no
+  /// value is followed by the next slot in the buffer; [`VecList::compact`] resets it
+  /// to `0.0`.
+  pub fn fragmentation_ratio(&self,) -> f64 {
+    let len = self.len();
+
+    if len < 2 { return 0.0 }
+
+    let mut breaks = 0;
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr {
+      ptr = unsafe { (*self.node(node,)).next };
+
+      if let Some(next) = ptr {
+        if next != node + 1 { breaks += 1 }
+      }
+    }
+
+    breaks as f64 / (len - 1) as f64
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_dump_layout() {
+    let mut list = VecList::with_capacity(4,);
+
+    for i in 0..3 { list.push_back(i,); }
+    list.pop_front();
+
+    let layout = list.dump_layout();
+
+    assert_eq!(layout.capacity, 4, "`VecList::dump_layout` incorrect capacity",);
+    assert_eq!(layout.len, 2, "`VecList::dump_layout` incorrect length",);
+    assert_eq!(layout.head, Some(1), "`VecList::dump_layout` incorrect head",);
+    assert_eq!(layout.tail, Some(2), "`VecList::dump_layout` incorrect tail",);
+    assert_eq!(layout.free_head, Some(0), "`VecList::dump_layout` incorrect free head",);
+    assert_eq!(layout.free_len, 1, "`VecList::dump_layout` incorrect free length",);
+    assert_eq!(
+      layout.slots,
+      vec![
+        SlotLayout { state: SlotState::Free, prev: None, next: None, generation: 1, },
+        SlotLayout { state: SlotState::Occupied, prev: None, next: Some(2), generation: 0, },
+        SlotLayout { state: SlotState::Occupied, prev: Some(1), next: None, generation: 0, },
+      ],
+      "`VecList::dump_layout` incorrect slots",
+    );
+
+    assert_eq!(list.free_slots(), 1, "`VecList::free_slots` incorrect count",);
+    assert_eq!(list.slot_high_water_mark(), 3, "`VecList::slot_high_water_mark` incorrect count",);
+  }
+  #[test]
+  fn test_fragmentation_ratio() {
+    let mut list = VecList::with_capacity(4,);
+
+    assert_eq!(list.fragmentation_ratio(), 0.0, "`VecList::fragmentation_ratio` incorrect ratio when empty",);
+    for i in 0..3 { list.push_back(i,); }
+    list.pop_front();
+    assert_eq!(list.fragmentation_ratio(), 0.0, "`VecList::fragmentation_ratio` incorrect ratio",);
+    list.push_back(3,);
+    assert_eq!(list.fragmentation_ratio(), 0.5, "`VecList::fragmentation_ratio` incorrect ratio",);
+  }
+  #[test]
+  fn test_memory_usage() {
+    let mut list = VecList::with_capacity(4,);
+
+    for i in 0..3 { list.push_back(i,); }
+    list.pop_front();
+
+    let usage = list.memory_usage();
+
+    assert_eq!(usage.bytes_allocated, 4 * (usage.node_overhead + mem::size_of::<i32>()), "`VecList::memory_usage` incorrect allocation",);
+    assert_eq!(usage.bytes_live, 2 * mem::size_of::<i32>(), "`VecList::memory_usage` incorrect live bytes",);
+    assert_eq!(usage.free_slots, 1, "`VecList::memory_usage` incorrect free slots",);
+  }
+  #[test]
+  fn test_is_contiguous() {
+    let mut list = (0..3).collect::<VecList<i32,>>();
+
+    assert!(list.is_contiguous(), "`VecList::is_contiguous` incorrect after collecting",);
+    list.pop_front();
+    assert!(!list.is_contiguous(), "`VecList::is_contiguous` ignored the empty slot",);
+    list.push_back(3,);
+    assert!(!list.is_contiguous(), "`VecList::is_contiguous` ignored the order",);
+    list.compact();
+    assert!(list.is_contiguous(), "`VecList::is_contiguous` incorrect after compacting",);
+    assert!(VecList::<i32,>::new().is_contiguous(), "`VecList::is_contiguous` incorrect when empty",);
+  }
+}
@@ -9,19 +9,73 @@
 
 extern crate imply_option;
 extern crate testdrop;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde",))]
+extern crate bincode;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 
 use std::{
-  ops::{RangeBounds, Bound, Drop,},
+  ops::{RangeBounds, Bound, Drop, Index, IndexMut,},
   iter::{FromIterator, Extend, TrustedLen,},
   num::NonZeroUsize,
+  mem::{self, ManuallyDrop,},
+  ptr,
+  cmp::Ordering,
+  alloc::{Global, Alloc,},
 };
 
 mod raw_vec;
 mod nodes;
+mod handles;
+mod errors;
+mod builder;
+mod layout;
+mod display;
+mod cmp;
+#[cfg(feature = "serde")]
+mod codec;
+mod cursor;
+mod pool;
+mod index;
+mod map;
+mod set;
+mod sort;
+#[cfg(feature = "rayon")]
+mod par_sort;
+mod search;
+mod convert;
+mod compact;
+mod split;
+mod retain;
+mod reorder;
+#[cfg(feature = "rand")]
+mod sample;
 mod iters;
+pub mod raw;
+pub mod lru;
+#[cfg(feature = "concurrent")]
+mod concurrent;
 
 use self::{nodes::*, raw_vec::*,};
-pub use self::iters::Drain;
+pub use self::{
+  iters::{
+    Iter, IterMut, Drain, Snapshot, IntoIter, DrainDuplicates, ExtractIf, ExtractWhile,
+    ControlFlow, Union, Intersection, Difference, SymmetricDifference,
+  },
+  handles::NodeId, errors::LengthMismatch,
+  builder::{VecListBuilder, GrowthPolicy, CompactionPolicy,},
+  layout::{ListLayout, SlotLayout, SlotState, MemoryUsage,},
+  display::DisplayWith,
+  cursor::{Cursor, CursorMut, CursorPos,},
+  pool::{CursorPool, PoolCursor,},
+  map::VecListMap, set::VecListSet,
+};
+#[cfg(feature = "concurrent")]
+pub use self::concurrent::{ConcurrentVecList, ShardedNodeId,};
 
 /// A [`VecList`] is an implementation of a Double Linked List.
 /// 
@@ -33,18 +87,28 @@ pub use self::iters::Drain;
 /// middle of a list a linked list has the advantage; hence backing a linked list with a
 /// buffer gives us the best of both worlds when making modifications in the middle of
 /// the list.
-pub struct VecList<T,> {
+/// 
+/// The buffer is allocated from `A`, which is cloned whenever the buffer is replaced,
+/// see [`VecList::with_capacity_in`].
+pub struct VecList<T, A: Alloc + Clone = Global,> {
   /// The underlying [`RawVec`] of [`Node`]s.
-  buf: RawVec<Node<T,>,>,
+  buf: RawVec<Node<T,>, A,>,
   /// The number of [`Node`]s in the [`VecList`]s buf.
   node_count: usize,
   /// The indexes to the ends of the linked list and the length of the linked list.
   ends: Option<(NonZeroUsize, usize, usize,)>,
   /// The index to the head of the stack of empty [`Node`]s and the size of the stack.
   empty: Option<(NonZeroUsize, usize,)>,
+  /// How `buf` grows when it runs out of space.
+  growth: GrowthPolicy,
+  /// When `buf` is compacted after values are removed.
+  compaction: CompactionPolicy,
+  /// The generation new slots start at; past the generation of every slot which was
+  /// forgotten by shrinking the buffer.
+  fresh_generation: usize,
 }
 
-impl<T,> VecList<T,> {
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
   /// Gets a reference to the [`Node`] at `ptr` in the [`VecList`]s buffer.
   #[inline]
   unsafe fn node(&self, ptr: usize,) -> *const Node<T,> {
@@ -66,40 +130,18 @@ impl<T,> VecList<T,> {
     (*self.node_mut(node,)).next = Some(next);
     (*self.node_mut(next,)).prev = Some(node);
   }
+  /// Gets a reference to the value of the [`Node`] at `ptr` in the [`VecList`]s buffer.
+  #[inline]
+  unsafe fn value(&self, ptr: usize,) -> &T { &*(*self.node(ptr,)).value }
 }
 
-impl<T,> VecList<T,> {
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
   /// Get the index to the [`Node`] at `index` in the [`VecList`].
   /// 
   /// # Panics
   /// 
   /// * If `index >= self.len()`
   fn ptr(&self, index: usize,) -> usize {
-    /// Get the pointer to the [`Node`] `steps` steps backwards from `link`.
-    /// 
-    /// # Params
-    /// 
-    /// list --- The [`VecList`] to get [`Node`]s from.  
-    /// link --- The [`Node`] to step from.  
-    /// steps --- The number of steps backwards to take from `link`.  
-    #[inline]
-    fn backwards<T,>(list: &VecList<T,>, link: usize, steps: usize,) -> usize {
-      if steps == 0 { link }
-      else { backwards(list, unsafe { (*list.buf.ptr().add(link)).prev() }, steps - 1,) }
-    }
-    /// Get the pointer to the [`Node`] `steps` steps frowards from `link`.
-    /// 
-    /// # Params
-    /// 
-    /// list --- The [`VecList`] to get [`Node`]s from.  
-    /// link --- The [`Node`] to step from.  
-    /// steps --- The number of steps frowards to take from `link`.  
-    #[inline]
-    fn forwards<T,>(list: &VecList<T,>, link: usize, steps: usize,) -> usize {
-      if steps == 0 { link }
-      else { forwards(list, unsafe { (*list.buf.ptr().add(link)).next() }, steps - 1,) }
-    }
-
     //Validate index.
     assert!(index < self.len(), "`VecList::ptr` index out of range",);
 
@@ -109,21 +151,75 @@ impl<T,> VecList<T,> {
     //Take the shortest number of steps.
     match self.ends.expect("`VecList::ptr` called on an empty `VecList`",) {
       //Go from the back.
-      (_, _, end,) if back_index < index => backwards(self, end, back_index,),
+      (_, _, end,) if back_index < index => self.backwards(end, back_index,),
       //Go from the front.
-      (_, start, _,) => forwards(self, start, index,),
+      (_, start, _,) => self.forwards(start, index,),
     }
   }
+  /// Get the pointer to the [`Node`] `steps` steps backwards from `link`.
+  /// 
+  /// # Params
+  /// 
+  /// link --- The [`Node`] to step from.  
+  /// steps --- The number of steps backwards to take from `link`.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If the start of the list is passed.
+  #[inline]
+  fn backwards(&self, mut link: usize, steps: usize,) -> usize {
+    for _ in 0..steps { link = unsafe { (*self.node(link,)).prev() } }
+
+    link
+  }
+  /// Get the pointer to the [`Node`] `steps` steps forwards from `link`.
+  /// 
+  /// # Params
+  /// 
+  /// link --- The [`Node`] to step from.  
+  /// steps --- The number of steps forwards to take from `link`.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If the end of the list is passed.
+  #[inline]
+  fn forwards(&self, mut link: usize, steps: usize,) -> usize {
+    for _ in 0..steps { link = unsafe { (*self.node(link,)).next() } }
+
+    link
+  }
+  /// Returns `true` if the slot of an empty [`Node`] with `generation` has run out of
+  /// generations.
+  /// 
+  /// The next generation would wrap the 32 bits stored in a [`NodeId`] so reusing the
+  /// slot could let a stale handle match a later value.
+  /// 
+  /// # Params
+  /// 
+  /// generation --- The (odd) generation of the empty [`Node`].  
+  #[inline]
+  fn exhausted(generation: usize,) -> bool { generation.wrapping_add(1,) as u32 == 0 }
   /// Allocate a new [`Node`] populated with `value`.
+  /// 
+  /// Empty [`Node`]s whose generation would wrap the 32 bits stored in a [`NodeId`] are
+  /// retired instead of reused so a stale handle can never match a later value.
   fn alloc_node(&mut self, value: T,) -> usize {
+    //Retire the empty `Node`s which have run out of generations.
+    while let Some((len, empty,)) = self.empty {
+      if !Self::exhausted(unsafe { (*self.node(empty,)).generation },) { break }
+
+      self.empty = unsafe { (*self.node_mut(empty,)).stack_pop() }
+        .and_then(|empty| NonZeroUsize::new(len.get() - 1,).map(|len| (len, empty,)),);
+    }
+
     match self.empty {
       //Allocate a new [`Node`] in the buffer.
       None => {
         let node = self.node_count;
 
-        self.node_count += 1;
         self.reserve(1,);
-        unsafe { *self.node_mut(node,) = Node::new(value,); }
+        self.node_count += 1;
+        unsafe { *self.node_mut(node,) = Node::new(value, self.fresh_generation,); }
 
         node
       },
@@ -132,7 +228,9 @@ impl<T,> VecList<T,> {
         self.empty = (*self.node_mut(empty,)).stack_pop()
           .map(|empty| (NonZeroUsize::new_unchecked(len.get() - 1,), empty,));
         
-        *self.node_mut(empty,) = Node::new(value,);
+        let generation = (*self.node(empty,)).generation.wrapping_add(1,);
+
+        *self.node_mut(empty,) = Node::new(value, generation,);
 
         empty
       } }
@@ -147,6 +245,7 @@ impl<T,> VecList<T,> {
     let node = unsafe { &mut *self.buf.ptr().add(ptr) };
 
     node.disconnect(self,);
+    node.generation = node.generation.wrapping_add(1,);
     self.empty = match self.empty {
       None => Some((unsafe { NonZeroUsize::new_unchecked(1,) }, ptr,)),
       Some((len, empty,)) => {
@@ -158,6 +257,192 @@ impl<T,> VecList<T,> {
 
     unsafe { (&*node.value as *const T).read() }
   }
+  /// Unlinks the [`Node`] at `ptr` from the list and deallocates it.
+  /// 
+  /// Returns the value of the [`Node`].
+  /// 
+  /// # Params
+  /// 
+  /// ptr --- The index of the [`Node`] in `buf`.  
+  fn unlink_node(&mut self, ptr: usize,) -> T {
+    let (len, head, tail,) = self.ends.expect("`VecList::unlink_node` called on an empty `VecList`");
+    let node = unsafe { &*self.node(ptr,) };
+
+    //Update the ends of the list.
+    self.ends = if head == tail { None }
+      else { Some((
+        unsafe { NonZeroUsize::new_unchecked(len.get() - 1,) },
+        if head == ptr { node.next() } else { head },
+        if tail == ptr { node.prev() } else { tail },
+      )) };
+
+    self.dealloc_node(ptr,)
+  }
+  /// Unlinks the `count` [`Node`]s from `start` to `end` inclusive out of the list.
+  /// 
+  /// The unlinked [`Node`]s stay linked to each other.
+  /// 
+  /// # Params
+  /// 
+  /// start --- The index of the first [`Node`] to unlink.  
+  /// end --- The index of the last [`Node`] to unlink.  
+  /// count --- The number of [`Node`]s from `start` to `end`.  
+  unsafe fn unlink_segment(&mut self, start: usize, end: usize, count: usize,) {
+    let (len, head, tail,) = self.ends.expect("`VecList::unlink_segment` called on an empty `VecList`");
+    let prev = (*self.node_mut(start,)).prev.take();
+    let next = (*self.node_mut(end,)).next.take();
+
+    //Link the `Node`s either side of the segment.
+    if let Some(prev) = prev { (*self.node_mut(prev,)).next = next }
+    if let Some(next) = next { (*self.node_mut(next,)).prev = prev }
+
+    self.ends = NonZeroUsize::new(len.get() - count,).map(|len| (
+      len,
+      if head == start { next.expect("`VecList::unlink_segment` no `Node` after the segment") } else { head },
+      if tail == end { prev.expect("`VecList::unlink_segment` no `Node` before the segment") } else { tail },
+    ));
+  }
+  /// Drops the values of `count` unlinked [`Node`]s and pushes them onto the empty stack
+  /// in one step.
+  /// 
+  /// # Params
+  /// 
+  /// start --- The index of the first [`Node`] to free.  
+  /// end --- The index of the last [`Node`] to free.  
+  /// count --- The number of [`Node`]s linked from `start` to `end`.  
+  unsafe fn free_segment(&mut self, start: usize, end: usize, count: usize,) {
+    //Drop the values.
+    let mut ptr = Some(start);
+    for _ in 0..count {
+      let node = &mut *self.node_mut(ptr.expect("`VecList::free_segment` segment too short"),);
+
+      ptr = node.next;
+      ManuallyDrop::drop(&mut node.value,);
+    }
+
+    self.release_segment(start, end, count,)
+  }
+  /// Pushes `count` unlinked [`Node`]s onto the empty stack in one step without dropping
+  /// their values.
+  /// 
+  /// # Params
+  /// 
+  /// start --- The index of the first [`Node`] to release.  
+  /// end --- The index of the last [`Node`] to release.  
+  /// count --- The number of [`Node`]s linked from `start` to `end`.  
+  unsafe fn release_segment(&mut self, start: usize, end: usize, count: usize,) {
+    let mut ptr = Some(start);
+    for _ in 0..count {
+      let node = &mut *self.node_mut(ptr.expect("`VecList::release_segment` segment too short"),);
+
+      ptr = node.next;
+      node.generation = node.generation.wrapping_add(1,);
+    }
+
+    //Push the whole segment onto the empty stack.
+    (*self.node_mut(end,)).next = self.empty.map(|(_, empty,)| empty);
+    self.empty = Some((
+      NonZeroUsize::new_unchecked(self.empty.map_or(0, |(len, _,)| len.get(),) + count,),
+      start,
+    ));
+  }
+  /// Links a chain of `count` unlinked [`Node`]s from `start` to `end` inclusive into
+  /// the list directly before the [`Node`] at `next`.
+  /// 
+  /// # Params
+  /// 
+  /// next --- The index of the [`Node`] to link before or `None` to link at the back.  
+  /// start --- The index of the first [`Node`] in the chain.  
+  /// end --- The index of the last [`Node`] in the chain.  
+  /// count --- The number of [`Node`]s in the chain.  
+  unsafe fn link_segment_before(&mut self, next: Option<usize>, start: usize, end: usize, count: usize,) {
+    self.ends = match (next, self.ends,) {
+      (_, None,) => Some((NonZeroUsize::new_unchecked(count,), start, end,)),
+      (Some(next), Some((len, head, tail,)),) => {
+        if let Some(prev) = (*self.node(next,)).prev { self.node_append(prev, start,) }
+        self.node_append(end, next,);
+
+        let head = if head == next { start } else { head };
+
+        Some((NonZeroUsize::new_unchecked(len.get() + count,), head, tail,))
+      },
+      (None, Some((len, head, tail,)),) => {
+        self.node_append(tail, start,);
+
+        Some((NonZeroUsize::new_unchecked(len.get() + count,), head, end,))
+      },
+    }
+  }
+  /// Moves every value out of `other` into a chain of unlinked [`Node`]s in this
+  /// [`VecList`], reserving space once, and leaves `other` empty.
+  /// 
+  /// Returns the first and last [`Node`]s of the chain and its length.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The [`VecList`] to move the values out of.  
+  fn adopt_nodes(&mut self, other: &mut Self,) -> Option<(usize, usize, usize,)> {
+    let (len, head, tail,) = other.ends?;
+
+    self.reserve(len.get(),);
+
+    let (mut ptr, mut chain,) = (Some(head), None,);
+    while let Some(node) = ptr {
+      let value = unsafe { ptr::read(&*(*other.node(node,)).value,) };
+      let new = self.alloc_node(value,);
+
+      chain = match chain {
+        None => Some((new, new,)),
+        Some((start, end,)) => {
+          unsafe { self.node_append(end, new,) }
+
+          Some((start, new,))
+        },
+      };
+      ptr = unsafe { (*other.node(node,)).next };
+    }
+
+    unsafe {
+      other.unlink_segment(head, tail, len.get(),);
+      other.release_segment(head, tail, len.get(),);
+    }
+
+    chain.map(|(start, end,)| (start, end, len.get(),))
+  }
+  /// Allocates a new [`Node`] populated with `value` and links it into the list
+  /// directly before the [`Node`] at `next`.
+  /// 
+  /// Returns the index of the new [`Node`].
+  /// 
+  /// # Params
+  /// 
+  /// next --- The index of the [`Node`] to insert before or `None` to insert at the back.  
+  /// value --- The value to insert.  
+  fn link_before(&mut self, next: Option<usize>, value: T,) -> usize {
+    match (next, self.ends,) {
+      (Some(next), Some((len, head, tail,)),) => {
+        let node = self.alloc_node(value,);
+
+        unsafe {
+          //Link the previous `Node` to the new `Node`.
+          if let Some(prev) = (*self.node(next,)).prev { self.node_append(prev, node,) }
+          self.node_append(node, next,);
+
+          let head = if head == next { node } else { head };
+
+          self.ends = Some((NonZeroUsize::new_unchecked(len.get() + 1,), head, tail,));
+        }
+
+        node
+      },
+      //Inserting at the back of the list.
+      _ => {
+        self.push_back(value,);
+
+        self.ends.expect("`VecList::link_before` `push_back` failed").2
+      },
+    }
+  }
 }
 
 impl<T,> VecList<T,> {
@@ -168,8 +453,68 @@ impl<T,> VecList<T,> {
   /// underlying buffer.
   #[inline]
   pub fn with_capacity(capacity: usize,) -> Self {
-    Self { buf: RawVec::with_capacity(capacity), node_count: 0, ends: None, empty: None, }
+    Self::with_capacity_and_policy(capacity, GrowthPolicy::default(),)
+  }
+  /// Constructs a new empty [`VecList`] with space for `capacity` nodes in the
+  /// underlying buffer which grows according to `growth`.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of [`Node`]s to allocate space for.  
+  /// growth --- How the buffer grows when it runs out of space.  
+  #[inline]
+  pub fn with_capacity_and_policy(capacity: usize, growth: GrowthPolicy,) -> Self {
+    Self {
+      buf: RawVec::with_capacity(capacity), node_count: 0, ends: None, empty: None,
+      growth, compaction: CompactionPolicy::default(), fresh_generation: 0,
+    }
+  }
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Constructs a new empty [`VecList`] whose buffer is allocated from `alloc`.
+  /// 
+  /// # Params
+  /// 
+  /// alloc --- The allocator to allocate the buffer from.  
+  #[inline]
+  pub fn new_in(alloc: A,) -> Self { Self::with_capacity_in(0, alloc,) }
+  /// Constructs a new empty [`VecList`] with space for `capacity` nodes in a buffer
+  /// allocated from `alloc`.
+  /// 
+  /// The conversions from other collections and the wrapping types ([`VecListMap`],
+  /// [`LruList`] and the like) always use the global allocator; use
+  /// [`Extend::extend`] to fill a [`VecList`] from another allocator.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of [`Node`]s to allocate space for.  
+  /// alloc --- The allocator to allocate the buffer from.  
+  #[inline]
+  pub fn with_capacity_in(capacity: usize, alloc: A,) -> Self {
+    Self {
+      buf: RawVec::with_capacity_in(capacity, alloc,), node_count: 0, ends: None, empty: None,
+      growth: GrowthPolicy::default(), compaction: CompactionPolicy::default(), fresh_generation: 0,
+    }
   }
+  /// Constructs a new empty [`VecList`] with space for `capacity` nodes and the same
+  /// allocator and policies as this [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of [`Node`]s to allocate space for.  
+  #[inline]
+  fn empty_like(&self, capacity: usize,) -> Self {
+    let mut list = Self::with_capacity_in(capacity, self.allocator().clone(),);
+
+    list.growth = self.growth;
+    list.compaction = self.compaction;
+
+    list
+  }
+  /// Returns the allocator the buffer is allocated from.
+  #[inline]
+  pub fn allocator(&self,) -> &A { self.buf.alloc() }
   /// Returns the capacity of the underlying buffer.
   #[inline]
   pub fn capacity(&self,) -> usize { self.buf.cap() }
@@ -190,6 +535,8 @@ impl<T,> VecList<T,> {
   }
   /// Reserves enough capacity for at least `additional` more elements to be inserted
   /// into the [`VecList`].
+  /// 
+  /// The buffer grows according to the [`GrowthPolicy`] of the [`VecList`].
   #[inline]
   pub fn reserve(&mut self, mut additional: usize,) {
     //Remove the empty `Node`s count from additional.
@@ -197,21 +544,192 @@ impl<T,> VecList<T,> {
       additional = additional.saturating_sub(empty.get(),)
     }
 
-    self.buf.reserve(self.node_count, additional,)
+    let step = match self.growth {
+      GrowthPolicy::Double => return self.buf.reserve(self.node_count, additional,),
+      GrowthPolicy::Exact => 1,
+      GrowthPolicy::Linear(step) => step.get(),
+    };
+    let required = self.node_count.checked_add(additional,)
+      .expect("`VecList::reserve` additional overflowed usize");
+
+    //Grow by at least `step` only if necessary.
+    if required > self.capacity() {
+      let cap = self.capacity();
+
+      self.buf.reserve_exact(cap, usize::max(required - cap, step,),)
+    }
   }
   /// Returns the number of elements in this [`VecList`].
   #[inline]
   pub fn len(&self,) -> usize {
     self.ends.map_or(0, |(len, _, _,)| len.get(),)
   }
+  /// Returns `true` if this [`VecList`] contains no elements.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.ends.is_none() }
+  /// Returns a reference to the value at `index` or `None` if `index` is out of range.
+  /// 
+  /// The value is found by walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the value.  
+  #[inline]
+  pub fn get(&self, index: usize,) -> Option<&T> {
+    if index < self.len() { Some(unsafe { self.value(self.ptr(index,),) }) }
+    else { None }
+  }
+  /// Returns a mutable reference to the value at `index` or `None` if `index` is out of
+  /// range.
+  /// 
+  /// The value is found by walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the value.  
+  #[inline]
+  pub fn get_mut(&mut self, index: usize,) -> Option<&mut T> {
+    if index < self.len() {
+      let ptr = self.ptr(index,);
+
+      Some(unsafe { &mut *(*self.node_mut(ptr,)).value })
+    } else { None }
+  }
+  /// Drops every value except the first `n` values.
+  /// 
+  /// The dropped values are unlinked in a single step after walking from whichever end
+  /// of the [`VecList`] is closer.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to keep.  
+  pub fn keep_first(&mut self, n: usize,) {
+    let len = self.len();
+
+    if n >= len { return }
+
+    let (_, _, tail,) = self.ends.expect("`VecList::keep_first` called on an empty `VecList`");
+    let start = self.ptr(n,);
+
+    unsafe {
+      self.unlink_segment(start, tail, len - n,);
+      self.free_segment(start, tail, len - n,);
+    }
+    self.after_removal();
+  }
+  /// Drops every value except the last `n` values.
+  /// 
+  /// The dropped values are unlinked in a single step after walking from whichever end
+  /// of the [`VecList`] is closer.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to keep.  
+  pub fn keep_last(&mut self, n: usize,) {
+    let len = self.len();
+
+    if n >= len { return }
+
+    let (_, head, _,) = self.ends.expect("`VecList::keep_last` called on an empty `VecList`");
+    let end = self.ptr(len - n - 1,);
+
+    unsafe {
+      self.unlink_segment(head, end, len - n,);
+      self.free_segment(head, end, len - n,);
+    }
+    self.after_removal();
+  }
+  /// Shortens the [`VecList`] to `len` values, dropping the rest.
+  /// 
+  /// The capacity is unchanged, see [`VecList::keep_first`].
+  /// 
+  /// # Params
+  /// 
+  /// len --- The number of values to keep.  
+  #[inline]
+  pub fn truncate(&mut self, len: usize,) { self.keep_first(len,) }
+  /// Shortens the [`VecList`] to its last `len` values, dropping the values before them.
+  /// 
+  /// The capacity is unchanged, see [`VecList::keep_last`].
+  /// 
+  /// # Params
+  /// 
+  /// len --- The number of values to keep.  
+  #[inline]
+  pub fn truncate_front(&mut self, len: usize,) { self.keep_last(len,) }
+  /// Drops the first `n` values or every value if there are fewer than `n`.
+  /// 
+  /// The capacity is unchanged, see [`VecList::keep_last`].
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to drop.  
+  #[inline]
+  pub fn drop_front(&mut self, n: usize,) {
+    let len = self.len().saturating_sub(n,);
+
+    self.keep_last(len,)
+  }
+  /// Resizes the [`VecList`] to `new_len` values, truncating it or pushing clones of
+  /// `value` onto the back.
+  /// 
+  /// Space for any new values is reserved once up front.
+  /// 
+  /// # Params
+  /// 
+  /// new_len --- The new length of the [`VecList`].  
+  /// value --- The value to extend with.  
+  pub fn resize(&mut self, new_len: usize, value: T,)
+    where T: Clone, {
+    let len = self.len();
+
+    if new_len <= len { return self.truncate(new_len,) }
+
+    self.reserve(new_len - len,);
+    for _ in len + 1..new_len { self.push_back(value.clone(),); }
+    self.push_back(value,);
+  }
+  /// Resizes the [`VecList`] to `new_len` values, truncating it or pushing values
+  /// generated by `f` onto the back.
+  /// 
+  /// Space for any new values is reserved once up front.
+  /// 
+  /// # Params
+  /// 
+  /// new_len --- The new length of the [`VecList`].  
+  /// f --- Generates the values to extend with.  
+  pub fn resize_with<F,>(&mut self, new_len: usize, mut f: F,)
+    where F: FnMut() -> T, {
+    let len = self.len();
+
+    if new_len <= len { return self.truncate(new_len,) }
+
+    self.reserve(new_len - len,);
+    for _ in len..new_len { self.push_back(f(),); }
+  }
   /// Clears all values from this [`VecList`].
   #[inline]
-  pub fn clear(&mut self,) { self.drain(..); }
+  pub fn clear(&mut self,) {
+    self.drop_values();
+    self.after_removal();
+  }
+  /// Drops all values in this [`VecList`] without consulting the compaction policy.
+  fn drop_values(&mut self,) {
+    if let Some((len, head, tail,)) = self.ends {
+      unsafe {
+        self.unlink_segment(head, tail, len.get(),);
+        self.free_segment(head, tail, len.get(),);
+      }
+    }
+  }
 }
 
-impl<T,> VecList<T,> {
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
   /// Pushes `value` onto the front of this [`VecList`].
-  pub fn push_front(&mut self, value: T,) {
+  /// 
+  /// Returns a handle which can be used to access `value` later without walking the
+  /// list.
+  pub fn push_front(&mut self, value: T,) -> NodeId {
     let node = self.alloc_node(value,);
 
     self.ends = unsafe { match self.ends {
@@ -222,22 +740,21 @@ impl<T,> VecList<T,> {
         Some((NonZeroUsize::new_unchecked(len.get() + 1,), node, tail,))
       },
     } };
+
+    self.handle(node,)
   }
   /// Pops a value off the front of this [`VecList`].
   #[inline]
   pub fn pop_front(&mut self,) -> Option<T> {
-    self.ends.take().map(|(len, head, tail,)| {
-      let head_node = unsafe { &mut *self.node_mut(head,) };
+    let value = self.ends.map(|(_, head, _,)| self.unlink_node(head,));
 
-      self.ends = if head == tail { None }
-        else { Some((unsafe { NonZeroUsize::new_unchecked(len.get() - 1,) }, head_node.next(), tail,)) };
-      head_node.disconnect(self,);
-
-      self.dealloc_node(head,)
-    })
+    self.after_removal(); value
   }
   /// Pushes `value` onto the back of this [`VecList`].
-  pub fn push_back(&mut self, value: T,) {
+  /// 
+  /// Returns a handle which can be used to access `value` later without walking the
+  /// list.
+  pub fn push_back(&mut self, value: T,) -> NodeId {
     let node = self.alloc_node(value,);
 
     self.ends = unsafe { match self.ends {
@@ -248,23 +765,358 @@ impl<T,> VecList<T,> {
         Some((NonZeroUsize::new_unchecked(len.get() + 1,), head, node,))
       },
     } };
+
+    self.handle(node,)
   }
   /// Pops a value off the back of this [`VecList`].
   #[inline]
   pub fn pop_back(&mut self,) -> Option<T> {
-    self.ends.take().map(|(len, head, tail,)| {
-      let tail_node = unsafe { &mut *self.node_mut(tail,) };
+    let value = self.ends.map(|(_, _, tail,)| self.unlink_node(tail,));
 
-      self.ends = if head == tail { None }
-        else { Some((unsafe { NonZeroUsize::new_unchecked(len.get() - 1,) }, head, tail_node.prev(),)) };
-      tail_node.disconnect(self,);
+    self.after_removal(); value
+  }
+  /// Pops the front value off this [`VecList`] if `pred` returns `true` for it.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- Returns `true` if the value should be popped.  
+  pub fn pop_front_if<P,>(&mut self, pred: P,) -> Option<T>
+    where P: FnOnce(&mut T,) -> bool, {
+    if pred(self.front_mut()?,) { self.pop_front() } else { None }
+  }
+  /// Pops the back value off this [`VecList`] if `pred` returns `true` for it.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- Returns `true` if the value should be popped.  
+  pub fn pop_back_if<P,>(&mut self, pred: P,) -> Option<T>
+    where P: FnOnce(&mut T,) -> bool, {
+    if pred(self.back_mut()?,) { self.pop_back() } else { None }
+  }
+  /// Returns a reference to the front value of this [`VecList`].
+  #[inline]
+  pub fn front(&self,) -> Option<&T> {
+    self.ends.map(|(_, head, _,)| unsafe { self.value(head,) })
+  }
+  /// Returns a mutable reference to the front value of this [`VecList`].
+  #[inline]
+  pub fn front_mut(&mut self,) -> Option<&mut T> {
+    let (_, head, _,) = self.ends?;
+
+    Some(unsafe { &mut *(*self.node_mut(head,)).value })
+  }
+  /// Returns a reference to the back value of this [`VecList`].
+  #[inline]
+  pub fn back(&self,) -> Option<&T> {
+    self.ends.map(|(_, _, tail,)| unsafe { self.value(tail,) })
+  }
+  /// Returns a mutable reference to the back value of this [`VecList`].
+  #[inline]
+  pub fn back_mut(&mut self,) -> Option<&mut T> {
+    let (_, _, tail,) = self.ends?;
 
-      self.dealloc_node(tail,)
-    })
+    Some(unsafe { &mut *(*self.node_mut(tail,)).value })
+  }
+  /// Inserts `value` at `index` so that it ends up at position `index` in the list.
+  /// 
+  /// The insertion point is found by walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to insert at.  
+  /// value --- The value to insert.  
+  /// 
+  /// # Errors
+  /// 
+  /// * If `index > self.len()`; `value` is handed back.
+  pub fn try_insert(&mut self, index: usize, value: T,) -> Result<(), T> {
+    if index > self.len() { return Err(value) }
+
+    let next = if index == self.len() { None } else { Some(self.ptr(index,)) };
+
+    self.link_before(next, value,);
+    Ok(())
+  }
+  /// Inserts `value` at `index` so that it ends up at position `index` in the list.
+  /// 
+  /// The insertion point is found by walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to insert at.  
+  /// value --- The value to insert.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index > self.len()`.
+  #[inline]
+  pub fn insert(&mut self, index: usize, value: T,) {
+    if self.try_insert(index, value,).is_err() {
+      panic!("`VecList::insert` index out of range")
+    }
+  }
+  /// Inserts every value of `iter` in order so the first ends up at position `index`.
+  /// 
+  /// The insertion point is found once by walking from whichever end is closer and
+  /// space is reserved up front from the size hint of `iter`.
+  /// 
+  /// Returns the number of values inserted.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to insert at.  
+  /// iter --- The values to insert.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index > self.len()`.
+  pub fn extend_at<I,>(&mut self, index: usize, iter: I,) -> usize
+    where I: IntoIterator<Item = T>, {
+    assert!(index <= self.len(), "`VecList::extend_at` index out of range",);
+
+    let iter = iter.into_iter();
+    let next = if index == self.len() { None } else { Some(self.ptr(index,)) };
+    let mut count = 0;
+
+    self.reserve(iter.size_hint().0,);
+    for value in iter {
+      self.link_before(next, value,);
+      count += 1;
+    }
+
+    count
+  }
+  /// Removes and returns the value at `index` or `None` if `index` is out of range.
+  /// 
+  /// The value is found by walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to remove from.  
+  pub fn try_remove(&mut self, index: usize,) -> Option<T> {
+    if index >= self.len() { return None }
+
+    let ptr = self.ptr(index,);
+    let value = self.unlink_node(ptr,);
+
+    self.after_removal();
+    Some(value)
+  }
+  /// Removes and returns the value at `index`.
+  /// 
+  /// The value is found by walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to remove from.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index >= self.len()`.
+  #[inline]
+  pub fn remove(&mut self, index: usize,) -> T {
+    self.try_remove(index,).expect("`VecList::remove` index out of range")
+  }
+  /// Removes and returns the value at `index`, replacing it with the back value.
+  /// 
+  /// The order of the values is not preserved but the slot of the removed value is
+  /// reused immediately for the back value. Handles to the removed value and to the
+  /// back value are invalidated.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position to remove from.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index >= self.len()`.
+  pub fn swap_remove_back(&mut self, index: usize,) -> T {
+    assert!(index < self.len(), "`VecList::swap_remove_back` index out of range",);
+
+    let (_, _, tail,) = self.ends.expect("`VecList::swap_remove_back` called on an empty `VecList`");
+    let ptr = self.ptr(index,);
+
+    let value = if ptr == tail { self.unlink_node(tail,) }
+      else {
+        let back = self.unlink_node(tail,);
+        let generation = unsafe { (*self.node(ptr,)).generation }.wrapping_add(1,);
+
+        //The slot has run out of generations; retire it and move the back value to a new slot.
+        if Self::exhausted(generation,) {
+          let next = unsafe { (*self.node(ptr,)).next };
+          let value = self.unlink_node(ptr,);
+
+          self.link_before(next, back,);
+          value
+        } else { unsafe {
+          let node = &mut *self.node_mut(ptr,);
+
+          //The slot holds a different value now.
+          node.generation = generation.wrapping_add(1,);
+          mem::replace(&mut *node.value, back,)
+        } }
+      };
+
+    self.after_removal(); value
+  }
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Inserts `value` into a sorted [`VecList`] and returns the handle to it.
+  /// 
+  /// `value` is inserted after any values which compare equal to it. The insertion point
+  /// is searched for from both ends at once so only `min(index, len - index)` steps are
+  /// taken to find it.
+  /// 
+  /// If the [`VecList`] is not sorted by `cmp` the insertion point is unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to insert.  
+  /// cmp --- The comparison the [`VecList`] is sorted by.  
+  pub fn insert_sorted_by<F,>(&mut self, value: T, mut cmp: F,) -> NodeId
+    where F: FnMut(&T, &T,) -> Ordering, {
+    let (mut front, mut back,) = match self.ends {
+      None => (None, None,),
+      Some((_, head, tail,)) => (Some(head), Some(tail),),
+    };
+    //Walk inwards from both ends until the insertion point is found.
+    let next = loop {
+      //Step forwards until a greater value is found.
+      match front {
+        Some(ptr) => if cmp(&value, unsafe { self.value(ptr,) },) == Ordering::Less { break Some(ptr) }
+          else { front = unsafe { (*self.node(ptr,)).next } },
+        None => break None,
+      }
+      //Step backwards until a lesser or equal value is found.
+      match back {
+        Some(ptr) => if cmp(&value, unsafe { self.value(ptr,) },) != Ordering::Less {
+            break unsafe { (*self.node(ptr,)).next }
+          } else { back = unsafe { (*self.node(ptr,)).prev } },
+        None => break self.ends.map(|(_, head, _,)| head),
+      }
+    };
+
+    let node = self.link_before(next, value,);
+
+    self.handle(node,)
+  }
+  /// Returns the index and handle of the first value which does not satisfy `pred`.
+  /// 
+  /// The [`VecList`] must be partitioned by `pred` so that all values which satisfy it
+  /// come before all values which do not. If every value satisfies `pred` the length of
+  /// the [`VecList`] and `None` is returned.
+  /// 
+  /// The search gallops inwards from both ends at once before narrowing down with a
+  /// binary search so the number of links walked is proportional to the distance of
+  /// the partition point from the nearest end.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate the [`VecList`] is partitioned by.  
+  pub fn partition_point<P,>(&self, mut pred: P,) -> (usize, Option<NodeId>,)
+    where P: FnMut(&T,) -> bool, {
+    let (len, head, tail,) = match self.ends {
+      Some((len, head, tail,)) => (len.get(), head, tail,),
+      None => return (0, None,),
+    };
+    //Every value before `lo` satisfies `pred` and no value from `hi` onwards does.
+    let (mut lo, mut hi,) = ((0, Some(head),), (len, None::<usize>,),);
+    //The number of values to skip in the next galloping probe.
+    let mut step = 1;
+    //Whether the next galloping probe is from the front.
+    let mut front = true;
+    //Whether the search is still galloping.
+    let mut galloping = true;
+
+    while lo.0 < hi.0 {
+      let width = hi.0 - lo.0;
+      //Choose the index to probe.
+      let mid = if !galloping { lo.0 + width / 2 }
+        else if front { lo.0 + usize::min(step, width,) - 1 }
+        else { hi.0 - usize::min(step, width,) };
+      //Walk to the probe from the nearest bound.
+      let ptr = if mid - lo.0 <= hi.0 - mid {
+        self.forwards(lo.1.expect("`VecList::partition_point` lower bound out of range"), mid - lo.0,)
+      } else {
+        match hi.1 {
+          Some(hi_ptr) => self.backwards(hi_ptr, hi.0 - mid,),
+          None => self.backwards(tail, len - 1 - mid,),
+        }
+      };
+
+      if pred(unsafe { self.value(ptr,) },) {
+        lo = (mid + 1, unsafe { (*self.node(ptr,)).next },);
+        //A successful probe from the back brackets the partition point.
+        if !front { galloping = false }
+      } else {
+        hi = (mid, Some(ptr),);
+        //A failed probe from the front brackets the partition point.
+        if front { galloping = false }
+      }
+
+      if galloping {
+        if !front { step *= 2 }
+        front = !front;
+      }
+    }
+
+    (lo.0, lo.1.map(|ptr| self.handle(ptr,),),)
+  }
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Calls `f` on every value in the [`VecList`] from front to back along with the
+  /// values either side of it.
+  /// 
+  /// The previous value has already been passed to `f` so it reflects any changes made
+  /// to it.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The function to call with the previous, current and next values.  
+  pub fn for_each_mut_with_neighbors<F,>(&mut self, mut f: F,)
+    where F: FnMut(Option<&T>, &mut T, Option<&T>,), {
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr { unsafe {
+      let node = &mut *self.node_mut(node,);
+      let prev = node.prev.map(|prev| self.value(prev,));
+      let next = node.next.map(|next| self.value(next,));
+
+      f(prev, &mut node.value, next,);
+      ptr = node.next;
+    } }
+  }
+  /// Walks the [`VecList`] and `other` in lockstep calling `f` on each pair of values.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The values to pair with the values in the [`VecList`].  
+  /// f --- The function to call with each pair of values.  
+  /// 
+  /// # Errors
+  /// 
+  /// * If `other` is not the same length as the [`VecList`]; no values are visited.
+  pub fn apply_paired<U, F,>(&mut self, other: &[U], mut f: F,) -> Result<(), LengthMismatch>
+    where F: FnMut(&mut T, &U,), {
+    if other.len() != self.len() {
+      return Err(LengthMismatch { expected: self.len(), found: other.len(), })
+    }
+
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+    for value in other {
+      let node = unsafe { &mut *self.node_mut(ptr.expect("`VecList::apply_paired` ran out of `Node`s"),) };
+
+      f(&mut node.value, value,);
+      ptr = node.next;
+    }
+
+    Ok(())
   }
 }
 
-impl<'t, T: 't,> VecList<T,> {
+impl<'t, T: 't, A: 't + Alloc + Clone,> VecList<T, A,> {
   /// Removes the elements in `range` from the [`VecList`] and returns them as an
   /// iterator.
   /// 
@@ -275,7 +1127,25 @@ impl<'t, T: 't,> VecList<T,> {
   /// # Panics
   /// 
   /// * If `range.end >= self.len()`.
-  pub fn drain<R,>(&'t mut self, range: R,) -> Drain<'t, T,>
+  pub fn drain<R,>(&'t mut self, range: R,) -> Drain<'t, T, A,>
+    where R: RangeBounds<usize>, {
+    let ends = self.range_ends(range,);
+
+    iters::drain(self, ends,)
+  }
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Resolves `range` to the first and last [`Node`]s in it.
+  /// 
+  /// # Params
+  /// 
+  /// range --- The range of indexes to resolve.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `range.end >= self.len()`.
+  fn range_ends<R,>(&self, range: R,) -> Option<(usize, usize,)>
     where R: RangeBounds<usize>, {
     use imply_option::ImplyOption;
 
@@ -287,32 +1157,30 @@ impl<'t, T: 't,> VecList<T,> {
     };
     //Get the ending index.
     let end = match range.end_bound() {
-      Bound::Excluded(&end,) => Some(end.saturating_sub(1)),
+      Bound::Excluded(&end,) => end.checked_sub(1,),
       Bound::Included(&end,) => Some(end),
       Bound::Unbounded => self.len().checked_sub(1),
     };
     //Validate the ends.
-    let ends = match end {
+    match end {
       Some(end) => if end < self.len() { (start <= end).then_do(|| (self.ptr(start), self.ptr(end),)) }
         else { panic!("The end of the range must be less than the length of the `VecList`") },
       None => None,
-    };
-
-    iters::drain(self, ends,)
+    }
   }
 }
 
-impl<T,> FromIterator<T> for VecList<T,> {
+impl<T, A: Alloc + Clone + Default,> FromIterator<T> for VecList<T, A,> {
   #[inline]
   fn from_iter<I,>(iter: I,) -> Self
     where I: IntoIterator<Item = T>, {
-    let mut list = VecList::new();
+    let mut list = VecList::new_in(A::default(),);
 
     list.extend(iter,); list
   }
 }
 
-impl<T,> Extend<T> for VecList<T,> {
+impl<T, A: Alloc + Clone,> Extend<T> for VecList<T, A,> {
   #[inline]
   fn extend<I,>(&mut self, iter: I,)
     where I: IntoIterator<Item = T>, {
@@ -320,17 +1188,17 @@ impl<T,> Extend<T> for VecList<T,> {
   }
 }
 
-impl<'t, T: 't + Clone,> FromIterator<&'t T> for VecList<T,> {
+impl<'t, T: 't + Clone, A: Alloc + Clone + Default,> FromIterator<&'t T> for VecList<T, A,> {
   #[inline]
   fn from_iter<I,>(iter: I,) -> Self
     where I: IntoIterator<Item = &'t T>, {
-    let mut list = VecList::new();
+    let mut list = VecList::new_in(A::default(),);
 
     list.extend(iter,); list
   }
 }
 
-impl<'t, T: 't + Clone,> Extend<&'t T> for VecList<T,> {
+impl<'t, T: 't + Clone, A: Alloc + Clone,> Extend<&'t T> for VecList<T, A,> {
   #[inline]
   fn extend<I,>(&mut self, iter: I,)
     where I: IntoIterator<Item = &'t T>, {
@@ -338,37 +1206,141 @@ impl<'t, T: 't + Clone,> Extend<&'t T> for VecList<T,> {
   }
 }
 
-trait SpecExtend<A, I,> {
+trait SpecExtend<U, I,> {
   fn spec_extend(&mut self, iter: I,)
-    where I: IntoIterator<Item = A>;
+    where I: IntoIterator<Item = U>;
 }
 
-impl<T, A, I,> SpecExtend<A, I,> for VecList<T,>
-  where A: Into<T>, I: Iterator<Item = A>, {
+impl<T, U, I, A: Alloc + Clone,> SpecExtend<U, I,> for VecList<T, A,>
+  where U: Into<T>, I: Iterator<Item = U>, {
   #[inline]
   default fn spec_extend(&mut self, iter: I,) {
-    for a in iter { self.push_back(a.into(),) }
+    for a in iter { self.push_back(a.into(),); }
   }
 }
 
-impl<T, A, I,> SpecExtend<A, I,> for VecList<T,>
-  where A: Into<T>, I: TrustedLen<Item = A>, {
+impl<T, U, I, A: Alloc + Clone,> SpecExtend<U, I,> for VecList<T, A,>
+  where U: Into<T>, I: TrustedLen<Item = U>, {
   fn spec_extend(&mut self, iter: I,) {
     //Reserve additional space for the values.
     if let Some(additional) = iter.size_hint().1 { self.reserve(additional,) }
 
-    for a in iter { self.push_back(a.into(),) }
+    for a in iter { self.push_back(a.into(),); }
+  }
+}
+
+impl<T, A: Alloc + Clone,> Index<usize> for VecList<T, A,> {
+  type Output = T;
+
+  /// Returns a reference to the value at `index`.
+  /// 
+  /// The value is found by walking from whichever end is closer so indexing takes
+  /// `O(min(index, len - index))` time.
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index >= self.len()`.
+  #[inline]
+  fn index(&self, index: usize,) -> &T {
+    self.get(index,).expect("`VecList::index` index out of range",)
+  }
+}
+
+impl<T, A: Alloc + Clone,> IndexMut<usize> for VecList<T, A,> {
+  /// Returns a mutable reference to the value at `index`.
+  /// 
+  /// The value is found by walking from whichever end is closer so indexing takes
+  /// `O(min(index, len - index))` time.
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index >= self.len()`.
+  #[inline]
+  fn index_mut(&mut self, index: usize,) -> &mut T {
+    self.get_mut(index,).expect("`VecList::index_mut` index out of range",)
+  }
+}
+
+impl<T: Clone, A: Alloc + Clone,> Clone for VecList<T, A,> {
+  /// Clones the [`VecList`] into a single allocation with the [`Node`]s laid out
+  /// contiguously in list order.
+  fn clone(&self,) -> Self {
+    let mut list = self.empty_like(self.len(),);
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr {
+      let node = unsafe { &*self.node(node,) };
+      let index = list.node_count;
+
+      unsafe {
+        *list.node_mut(index,) = Node::new((*node.value).clone(), 0,);
+        if let Some(prev) = index.checked_sub(1,) { list.node_append(prev, index,) }
+      }
+      //Keep the clone consistent in case the next clone panics.
+      list.node_count += 1;
+      list.ends = Some((unsafe { NonZeroUsize::new_unchecked(index + 1,) }, 0, index,));
+      ptr = node.next;
+    }
+
+    list
+  }
+  /// Clones `source` into this [`VecList`] reusing its buffer and empty [`Node`]s.
+  /// 
+  /// Values already in this [`VecList`] are cloned into in place and any extra values
+  /// are dropped in a single step.
+  /// 
+  /// # Params
+  /// 
+  /// source --- The [`VecList`] to clone.  
+  fn clone_from(&mut self, source: &Self,) {
+    let (mut ptr, mut src,) = (self.ends.map(|(_, head, _,)| head), source.ends.map(|(_, head, _,)| head),);
+    let mut count = 0;
+
+    //Clone into the live values.
+    while let (Some(node), Some(other),) = (ptr, src,) {
+      unsafe {
+        let other = &*source.node(other,);
+
+        (*(*self.node_mut(node,)).value).clone_from(&*other.value,);
+        ptr = (*self.node(node,)).next;
+        src = other.next;
+      }
+
+      count += 1;
+    }
+
+    self.growth = source.growth;
+    self.compaction = source.compaction;
+    match ptr {
+      //Drop the extra values.
+      Some(start) => unsafe {
+        let (len, _, tail,) = self.ends.expect("`VecList::clone_from` called on an empty `VecList`");
+
+        self.unlink_segment(start, tail, len.get() - count,);
+        self.free_segment(start, tail, len.get() - count,);
+      },
+      //Push the remaining values.
+      None => {
+        self.reserve(source.len() - count,);
+        while let Some(other) = src {
+          let other = unsafe { &*source.node(other,) };
+
+          self.push_back((*other.value).clone(),);
+          src = other.next;
+        }
+      },
+    }
   }
 }
 
-impl<T,> Default for VecList<T,> {
+impl<T, A: Alloc + Clone + Default,> Default for VecList<T, A,> {
   #[inline]
-  fn default() -> Self { Self::new() }
+  fn default() -> Self { Self::new_in(A::default(),) }
 }
 
-impl<T,> Drop for VecList<T,> {
+impl<T, A: Alloc + Clone,> Drop for VecList<T, A,> {
   #[inline]
-  fn drop(&mut self,) { self.clear() }
+  fn drop(&mut self,) { self.drop_values() }
 }
 
 #[cfg(test)]
@@ -386,6 +1358,7 @@ mod tests {
 
     assert_eq!(list.capacity(), 2, "`VecList::with_capacity(2,)` created with incorrect capacity",);
     assert_eq!(list.len(), 0, "`VecList::with_capacity(2,)` created with incorrect length",);
+    assert!(list.is_empty() && list.front().is_none(), "`VecList::with_capacity(2,)` created non-empty",);
 
     list.reserve(1,);
     assert_eq!(list.capacity(), 2, "`VecList::reserve(1,)` incorrect capacity",);
@@ -412,4 +1385,221 @@ mod tests {
     assert_eq!(list.capacity(), 10, "`VecList::clear` changed the capacity",);
     assert_eq!(list.len(), 0, "`VecList::clear` did not empty the list",);
   }
+  #[test]
+  fn test_get() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    assert_eq!((list.get(0,), list.get(2,), list.get(3,),), (Some(&1), Some(&3), None,), "`VecList::get` returned the wrong value",);
+    *list.get_mut(1,).expect("`VecList::get_mut` returned `None`") = 4;
+    assert_eq!(list.get_mut(3,), None, "`VecList::get_mut` returned a value out of range",);
+    assert_eq!(list.to_vec(), vec![1, 4, 3,], "`VecList::get_mut` did not write the value",);
+  }
+  #[test]
+  fn test_index() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    list[2] = 4;
+    assert_eq!((list[0], list[1], list[2],), (1, 2, 4,), "`VecList::index_mut` did not write the value",);
+  }
+  #[test]
+  #[should_panic]
+  fn test_index_out_of_range() {
+    let list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    let _ = list[3];
+  }
+  #[test]
+  fn test_front_mut() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    *list.front_mut().expect("`VecList::front_mut` returned `None`") -= 1;
+    *list.back_mut().expect("`VecList::back_mut` returned `None`") += 1;
+    assert_eq!((list.front(), list.back(),), (Some(&0), Some(&4),), "`VecList::(front/back)_mut` did not write the value",);
+    list.clear();
+    assert!(list.front_mut().is_none() && list.back_mut().is_none(), "`VecList::(front/back)_mut` returned a value when empty",);
+  }
+  #[test]
+  fn test_pop_if() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<_,>>();
+
+    assert_eq!(list.pop_front_if(|&mut value,| value > 0,), None, "`VecList::pop_front_if` popped a rejected value",);
+    assert_eq!(list.pop_back_if(|&mut value,| value > 1,), Some(2), "`VecList::pop_back_if` did not pop",);
+    assert_eq!(list.pop_front_if(|value,| { *value += 1; true },), Some(1), "`VecList::pop_front_if` did not pop",);
+    assert_eq!(list.to_vec(), vec![1,], "`VecList::pop_(front/back)_if` removed the wrong values",);
+  }
+  #[test]
+  fn test_insert_remove() {
+    let mut list = vec![1, 3,].into_iter().collect::<VecList<_,>>();
+
+    list.insert(1, 2,);
+    list.insert(3, 4,);
+    list.insert(0, 0,);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4,], "`VecList::insert` inserted incorrectly",);
+    assert_eq!(list.try_insert(6, 5,), Err(5), "`VecList::try_insert` inserted out of range",);
+    assert_eq!(list.remove(3,), 3, "`VecList::remove` removed the wrong value",);
+    assert_eq!(list.try_remove(4,), None, "`VecList::try_remove` removed out of range",);
+    assert_eq!(list.try_remove(0,), Some(0), "`VecList::try_remove` removed the wrong value",);
+    assert_eq!(list.to_vec(), vec![1, 2, 4,], "`VecList::remove` removed incorrectly",);
+  }
+  #[test]
+  fn test_extend_at() {
+    let mut list = vec![1, 4,].into_iter().collect::<VecList<_,>>();
+
+    assert_eq!(list.extend_at(1, vec![2, 3,],), 2, "`VecList::extend_at` incorrect count",);
+    assert_eq!(list.extend_at(4, 5..7,), 2, "`VecList::extend_at` incorrect count",);
+    assert_eq!(list.extend_at(0, None,), 0, "`VecList::extend_at` incorrect count",);
+    assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5, 6,], "`VecList::extend_at` inserted incorrectly",);
+  }
+  #[test]
+  fn test_swap_remove_back() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    assert_eq!(list.swap_remove_back(0,), 1, "`VecList::swap_remove_back` removed the wrong value",);
+    assert_eq!(list.to_vec(), vec![3, 2,], "`VecList::swap_remove_back` did not move the back value",);
+    assert_eq!(list.swap_remove_back(1,), 2, "`VecList::swap_remove_back` removed the wrong value",);
+    assert_eq!(list.to_vec(), vec![3,], "`VecList::swap_remove_back` removed the back incorrectly",);
+  }
+  #[test]
+  fn test_keep() {
+    let mut list = (0..6).collect::<VecList<_,>>();
+
+    list.keep_first(4,);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3,], "`VecList::keep_first` kept the wrong values",);
+    list.keep_last(2,);
+    assert_eq!(list.to_vec(), vec![2, 3,], "`VecList::keep_last` kept the wrong values",);
+    list.keep_first(3,);
+    list.keep_last(3,);
+    assert_eq!(list.to_vec(), vec![2, 3,], "`VecList::keep_(first/last)` removed values when longer",);
+    list.keep_last(0,);
+    assert!(list.is_empty(), "`VecList::keep_last(0,)` kept values",);
+  }
+  #[test]
+  fn test_truncate() {
+    let mut list = (0..4).collect::<VecList<_,>>();
+
+    list.truncate(2,);
+    assert_eq!(list.to_vec(), vec![0, 1,], "`VecList::truncate` kept the wrong values",);
+    list.truncate(3,);
+    assert_eq!(list.len(), 2, "`VecList::truncate` changed a shorter list",);
+    list.truncate(0,);
+    assert!(list.is_empty(), "`VecList::truncate(0,)` kept values",);
+  }
+  #[test]
+  fn test_truncate_front() {
+    let mut list = (4..9).collect::<VecList<_,>>();
+
+    list.truncate_front(4,);
+    assert_eq!(list.to_vec(), vec![5, 6, 7, 8,], "`VecList::truncate_front` kept the wrong values",);
+    list.drop_front(3,);
+    assert_eq!(list.to_vec(), vec![8,], "`VecList::drop_front` kept the wrong values",);
+    list.drop_front(2,);
+    assert!(list.is_empty(), "`VecList::drop_front` kept values",);
+  }
+  #[test]
+  fn test_resize() {
+    let mut list = vec![8,].into_iter().collect::<VecList<_,>>();
+
+    list.resize(3, 1,);
+    assert_eq!(list.to_vec(), vec![8, 1, 1,], "`VecList::resize` extended incorrectly",);
+    let mut next = 0;
+    list.resize_with(5, || { next += 1; next },);
+    assert_eq!(list.to_vec(), vec![8, 1, 1, 1, 2,], "`VecList::resize_with` extended incorrectly",);
+    list.resize(2, 0,);
+    assert_eq!(list.to_vec(), vec![8, 1,], "`VecList::resize` truncated incorrectly",);
+  }
+  #[test]
+  fn test_insert_sorted_by() {
+    let mut list = VecList::new();
+
+    for &value in &[(2, 0,), (0, 1,), (3, 2,), (2, 3,), (1, 4,),] {
+      let id = list.insert_sorted_by(value, |a, b,| a.0.cmp(&b.0,),);
+
+      assert_eq!(list.get_by_handle(id,), Some(&value), "`VecList::insert_sorted_by` returned the wrong handle",);
+    }
+    assert_eq!(list.to_vec(), vec![(0, 1,), (1, 4,), (2, 0,), (2, 3,), (3, 2,),], "`VecList::insert_sorted_by` inserted incorrectly",);
+  }
+  #[test]
+  fn test_partition_point() {
+    let list = (0..10).collect::<VecList<_,>>();
+
+    for split in 0..11 {
+      let (index, id,) = list.partition_point(|&value,| value < split,);
+
+      assert_eq!(index, split, "`VecList::partition_point` returned the wrong index",);
+      assert_eq!(id.and_then(|id,| list.get_by_handle(id,),), list.get(split,), "`VecList::partition_point` returned the wrong handle",);
+    }
+    assert_eq!(VecList::<i32>::new().partition_point(|_,| true,), (0, None,), "`VecList::partition_point` failed when empty",);
+  }
+  #[test]
+  fn test_for_each_mut_with_neighbors() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    list.for_each_mut_with_neighbors(|prev, value, next,| *value += prev.cloned().unwrap_or(0,) + next.cloned().unwrap_or(0,),);
+    assert_eq!(list.to_vec(), vec![3, 8, 11,], "`VecList::for_each_mut_with_neighbors` passed the wrong neighbours",);
+  }
+  #[test]
+  fn test_apply_paired() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    assert_eq!(list.apply_paired(&[10, 20, 30,], |value, other,| *value += other,), Ok(()), "`VecList::apply_paired` failed",);
+    assert_eq!(list.to_vec(), vec![11, 22, 33,], "`VecList::apply_paired` paired the wrong values",);
+    assert_eq!(list.apply_paired(&[1,], |value, other,| *value += other,), Err(LengthMismatch { expected: 3, found: 1, }),
+      "`VecList::apply_paired` accepted a mismatched length",);
+    assert_eq!(list.to_vec(), vec![11, 22, 33,], "`VecList::apply_paired` visited values on error",);
+  }
+  #[test]
+  fn test_clone() {
+    let mut list = vec![1, 2, 3,].into_iter().collect::<VecList<_,>>();
+
+    list.pop_front();
+    list.push_front(0,);
+
+    let clone = list.clone();
+
+    assert_eq!(clone.to_vec(), list.to_vec(), "`VecList::clone` incorrect values",);
+    assert_eq!(clone.capacity(), list.len(), "`VecList::clone` incorrect capacity",);
+    assert!(clone.is_contiguous(), "`VecList::clone` did not lay the values out in order",);
+  }
+  #[test]
+  fn test_clone_from() {
+    let list = vec![1, 2,].into_iter().collect::<VecList<_,>>();
+    let mut clone = VecList::with_capacity(4,);
+
+    clone.extend(0..4,);
+    clone.clone_from(&list,);
+    assert_eq!(clone.to_vec(), list.to_vec(), "`VecList::clone_from` incorrect values",);
+    assert_eq!(clone.capacity(), 4, "`VecList::clone_from` reallocated the buffer",);
+  }
+  #[test]
+  fn test_allocator() {
+    //Generic over the allocator so every call is checked against any `A`.
+    fn check<A: Alloc + Clone,>(mut list: VecList<i32, A,>,) {
+      list.extend(vec![3, 1, 2,],);
+      list.sort();
+      assert_eq!(list.iter().cloned().collect::<Vec<_,>>(), vec![1, 2, 3,], "`VecList::iter` incorrect values",);
+      list.insert_sorted_by(0, i32::cmp,);
+      assert_eq!(list[0], 0, "`VecList::insert_sorted_by` inserted incorrectly",);
+
+      let copy = list.clone();
+
+      assert_eq!(list, copy, "`VecList::clone` incorrect values",);
+      {
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1), "`CursorMut::remove_current` removed the wrong value",);
+      }
+      assert_eq!(list.drain(..,).collect::<Vec<_,>>(), vec![0, 2, 3,], "`VecList::drain` incorrect values",);
+      assert_eq!(copy.into_iter().collect::<Vec<_,>>(), vec![0, 1, 2, 3,], "`VecList::into_iter` incorrect values",);
+    }
+
+    let mut list = VecList::<i32, Global,>::with_capacity_in(2, Global,);
+
+    assert_eq!(list.capacity(), 2, "`VecList::with_capacity_in` created with incorrect capacity",);
+    list.push_back(1,); list.push_front(0,); list.push_back(2,);
+    assert_eq!((list.front(), list.back(), list.len(),), (Some(&0), Some(&2), 3,), "`VecList::with_capacity_in` incorrect values",);
+    list.clear();
+    assert!(list.is_empty() && list.free_slots() == 3, "`VecList::clear` did not free the `Node`s",);
+    check(list,);
+  }
 }
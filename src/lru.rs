@@ -0,0 +1,207 @@
+//! A least recently used cache built on a [`VecList`].
+//! 
+//! Entries are kept in a [`VecList`] ordered from most to least recently used and the
+//! hash of each key is mapped to the [`NodeId`] of its entry so lookups, promotions and
+//! evictions never walk the list. Each key is only stored once, in its entry.
+
+use {VecList,};
+use index::{KeyIndex, Entry,};
+use std::{hash::Hash, borrow::Borrow, fmt,};
+
+/// A fixed capacity cache which evicts the least recently used entry when full.
+pub struct LruList<K, V,> {
+  /// The entries from most to least recently used.
+  list: VecList<Entry<K, V,>,>,
+  /// Finds the entry of each key.
+  index: KeyIndex,
+  /// The maximum number of entries.
+  capacity: usize,
+  /// Called with each evicted entry.
+  on_evict: Option<Box<dyn FnMut(K, V,)>>,
+}
+
+impl<K: Hash + Eq, V,> LruList<K, V,> {
+  /// Constructs a new empty [`LruList`] which holds at most `capacity` entries.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The maximum number of entries.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `capacity == 0`.
+  pub fn new(capacity: usize,) -> Self {
+    assert!(capacity > 0, "`LruList::new` capacity must be greater than zero",);
+
+    Self {
+      list: VecList::with_capacity(capacity,),
+      index: KeyIndex::with_capacity(capacity,),
+      capacity,
+      on_evict: None,
+    }
+  }
+  /// Constructs a new empty [`LruList`] which holds at most `capacity` entries and calls
+  /// `on_evict` with every entry it evicts.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The maximum number of entries.  
+  /// on_evict --- Called with each evicted entry.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `capacity == 0`.
+  pub fn with_eviction<F,>(capacity: usize, on_evict: F,) -> Self
+    where F: 'static + FnMut(K, V,), {
+    Self { on_evict: Some(Box::new(on_evict,),), ..Self::new(capacity,) }
+  }
+  /// Returns the maximum number of entries.
+  #[inline]
+  pub fn capacity(&self,) -> usize { self.capacity }
+  /// Returns the number of entries.
+  #[inline]
+  pub fn len(&self,) -> usize { self.list.len() }
+  /// Returns `true` if there are no entries.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.len() == 0 }
+  /// Returns `true` if there is an entry for `key`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  #[inline]
+  pub fn contains_key<Q,>(&self, key: &Q,) -> bool
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    self.index.get(&self.list, key,).is_some()
+  }
+  /// Returns a reference to the value for `key` and marks it as most recently used.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  pub fn get<Q,>(&mut self, key: &Q,) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.list.move_to_front(id,);
+    self.list.get_by_handle(id,).map(|entry,| &entry.value,)
+  }
+  /// Returns a mutable reference to the value for `key` and marks it as most recently
+  /// used.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  pub fn get_mut<Q,>(&mut self, key: &Q,) -> Option<&mut V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.list.move_to_front(id,);
+    self.list.get_by_handle_mut(id,).map(|entry,| &mut entry.value,)
+  }
+  /// Returns a reference to the value for `key` without marking it as used.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  pub fn peek<Q,>(&self, key: &Q,) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.list.get_by_handle(id,).map(|entry,| &entry.value,)
+  }
+  /// Inserts `value` for `key` as the most recently used entry.
+  /// 
+  /// If the cache is full the least recently used entry is evicted first. Returns the
+  /// previous value for `key` if there was one.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key of the entry.  
+  /// value --- The value of the entry.  
+  pub fn put(&mut self, key: K, value: V,) -> Option<V> {
+    if let Some(id) = self.index.get(&self.list, &key,) {
+      self.list.move_to_front(id,);
+
+      return Some(::std::mem::replace(&mut self.list[id].value, value,))
+    }
+
+    if self.len() >= self.capacity {
+      if let Some((key, value,)) = self.pop_lru() {
+        if let Some(ref mut on_evict) = self.on_evict { on_evict(key, value,) }
+      }
+    }
+
+    let id = self.list.push_front(Entry::new(key, value,),);
+
+    self.index.insert(&mut self.list, id,);
+
+    None
+  }
+  /// Removes the entry for `key` and returns its value.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key of the entry.  
+  pub fn remove<Q,>(&mut self, key: &Q,) -> Option<V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.index.remove(&mut self.list, id,);
+    self.list.remove_by_handle(id,).map(|entry,| entry.value,)
+  }
+  /// Removes the least recently used entry without calling the eviction callback.
+  pub fn pop_lru(&mut self,) -> Option<(K, V,)> {
+    let id = self.list.back_handle()?;
+
+    self.index.remove(&mut self.list, id,);
+    self.list.remove_by_handle(id,).map(|entry,| (entry.key, entry.value,),)
+  }
+  /// Returns an iterator over the entries from most to least recently used.
+  #[inline]
+  pub fn iter(&self,) -> impl DoubleEndedIterator<Item = (&K, &V,)> {
+    self.list.iter().map(|entry,| (&entry.key, &entry.value,),)
+  }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug,> fmt::Debug for LruList<K, V,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_map().entries(self.list.iter().map(|entry,| (&entry.key, &entry.value,),),).finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::{rc::Rc, cell::RefCell,};
+
+  #[test]
+  fn test_lru() {
+    let evicted = Rc::new(RefCell::new(Vec::<(&str, i32,)>::new(),),);
+    let mut lru = {
+      let evicted = evicted.clone();
+
+      LruList::with_eviction(2, move |key, value,| evicted.borrow_mut().push((key, value,),),)
+    };
+
+    assert_eq!(lru.put("a", 1,), None, "`LruList::put` returned a value for a new key",);
+    lru.put("b", 2,);
+    assert_eq!(lru.get("a",), Some(&1), "`LruList::get` returned the wrong value",);
+    lru.put("c", 3,);
+    assert_eq!(*(*evicted).borrow(), vec![("b", 2,),], "`LruList::put` evicted the wrong entry",);
+    assert_eq!(lru.put("a", 4,), Some(1), "`LruList::put` did not replace the value",);
+    assert_eq!(lru.iter().collect::<Vec<_,>>(), vec![(&"a", &4,), (&"c", &3,),], "`LruList` incorrect order",);
+    assert_eq!(lru.remove("c",), Some(3), "`LruList::remove` returned the wrong value",);
+    assert_eq!((lru.len(), lru.capacity(),), (1, 2,), "`LruList` incorrect length",);
+
+    //Keys are only stored once so they need not be `Clone`.
+    #[derive(PartialEq, Eq, Hash, Debug,)]
+    struct Key(i32,);
+
+    let mut lru = LruList::new(1,);
+
+    lru.put(Key(1,), 1,);
+    lru.put(Key(2,), 2,);
+    assert_eq!((lru.peek(&Key(1,),), lru.peek(&Key(2,),),), (None, Some(&2),), "`LruList::put` evicted the wrong entry",);
+  }
+}
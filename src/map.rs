@@ -0,0 +1,220 @@
+
+use {VecList,};
+use index::{KeyIndex, Entry,};
+use std::{hash::Hash, borrow::Borrow, fmt, mem,};
+
+/// A map which remembers the order its keys were inserted in.
+/// 
+/// The entries are stored in a [`VecList`] in insertion order and the hash of each key
+/// is mapped to the [`NodeId`] of its entry so entries can be found, removed and
+/// reordered without walking the list. Each key is only stored once, in its entry.
+pub struct VecListMap<K, V,> {
+  /// The entries in insertion order.
+  list: VecList<Entry<K, V,>,>,
+  /// Finds the entry of each key.
+  index: KeyIndex,
+}
+
+impl<K: Hash + Eq, V,> VecListMap<K, V,> {
+  /// Constructs a new empty [`VecListMap`].
+  #[inline]
+  pub fn new() -> Self { Self::with_capacity(0,) }
+  /// Constructs a new empty [`VecListMap`] with space for `capacity` entries.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of entries to allocate space for.  
+  #[inline]
+  pub fn with_capacity(capacity: usize,) -> Self {
+    Self { list: VecList::with_capacity(capacity,), index: KeyIndex::with_capacity(capacity,), }
+  }
+  /// Returns the number of entries.
+  #[inline]
+  pub fn len(&self,) -> usize { self.list.len() }
+  /// Returns `true` if there are no entries.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.len() == 0 }
+  /// Returns `true` if there is an entry for `key`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  #[inline]
+  pub fn contains_key<Q,>(&self, key: &Q,) -> bool
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    self.index.get(&self.list, key,).is_some()
+  }
+  /// Returns a reference to the value for `key`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  pub fn get<Q,>(&self, key: &Q,) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.list.get_by_handle(id,).map(|entry,| &entry.value,)
+  }
+  /// Returns a mutable reference to the value for `key`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to look up.  
+  pub fn get_mut<Q,>(&mut self, key: &Q,) -> Option<&mut V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.list.get_by_handle_mut(id,).map(|entry,| &mut entry.value,)
+  }
+  /// Inserts `value` for `key`.
+  /// 
+  /// A new key is added at the back; an existing key keeps its position and has its
+  /// value replaced. Returns the previous value for `key` if there was one.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key of the entry.  
+  /// value --- The value of the entry.  
+  pub fn insert(&mut self, key: K, value: V,) -> Option<V> {
+    if let Some(id) = self.index.get(&self.list, &key,) {
+      return Some(mem::replace(&mut self.list[id].value, value,))
+    }
+
+    let id = self.list.push_back(Entry::new(key, value,),);
+
+    self.index.insert(&mut self.list, id,);
+
+    None
+  }
+  /// Removes the entry for `key` keeping the order of the other entries and returns its
+  /// value.
+  /// 
+  /// Unlike a map backed by a [`Vec`] no entries are shifted so this is `O(1)`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key of the entry.  
+  pub fn shift_remove<Q,>(&mut self, key: &Q,) -> Option<V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    let id = self.index.get(&self.list, key,)?;
+
+    self.index.remove(&mut self.list, id,);
+    self.list.remove_by_handle(id,).map(|entry,| entry.value,)
+  }
+  /// Moves the entry for `key` to the back of the order.
+  /// 
+  /// Returns `false` if there is no entry for `key`.
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key of the entry.  
+  pub fn move_to_back<Q,>(&mut self, key: &Q,) -> bool
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    match self.index.get(&self.list, key,) {
+      Some(id) => self.list.move_to_back(id,),
+      None => false,
+    }
+  }
+  /// Keeps only the entries for which `f` returns `true`, walking in insertion order.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Returns `true` for the entries to keep.  
+  pub fn retain<F,>(&mut self, mut f: F,)
+    where F: FnMut(&K, &mut V,) -> bool, {
+    let mut next = self.list.front_handle();
+
+    while let Some(id) = next {
+      next = self.list.next_handle(id,);
+
+      let keep = {
+        let entry = &mut self.list[id];
+
+        f(&entry.key, &mut entry.value,)
+      };
+
+      if !keep {
+        self.index.remove(&mut self.list, id,);
+        self.list.remove_by_handle(id,);
+      }
+    }
+  }
+  /// Removes the oldest entry.
+  pub fn pop_front(&mut self,) -> Option<(K, V,)> {
+    let id = self.list.front_handle()?;
+
+    self.index.remove(&mut self.list, id,);
+    self.list.remove_by_handle(id,).map(|entry,| (entry.key, entry.value,),)
+  }
+  /// Returns an iterator over the entries in insertion order.
+  #[inline]
+  pub fn iter(&self,) -> impl DoubleEndedIterator<Item = (&K, &V,)> {
+    self.list.iter().map(|entry,| (&entry.key, &entry.value,),)
+  }
+  /// Returns an iterator over the keys in insertion order.
+  #[inline]
+  pub fn keys(&self,) -> impl DoubleEndedIterator<Item = &K> {
+    self.list.iter().map(|entry,| &entry.key,)
+  }
+  /// Returns an iterator over the values in insertion order.
+  #[inline]
+  pub fn values(&self,) -> impl DoubleEndedIterator<Item = &V> {
+    self.list.iter().map(|entry,| &entry.value,)
+  }
+}
+
+impl<K: Hash + Eq, V,> Default for VecListMap<K, V,> {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+impl<K: fmt::Debug, V,> VecListMap<K, V,> {
+  /// Formats the keys as a set in insertion order.
+  pub(crate) fn fmt_keys(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_set().entries(self.list.iter().map(|entry,| &entry.key,),).finish()
+  }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug,> fmt::Debug for VecListMap<K, V,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_map().entries(self.list.iter().map(|entry,| (&entry.key, &entry.value,),),).finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_map() {
+    let mut map = VecListMap::new();
+
+    map.insert("b", 1,);
+    map.insert("a", 2,);
+    map.insert("c", 3,);
+    assert_eq!(map.insert("b", 4,), Some(1), "`VecListMap::insert` did not replace the value",);
+    assert_eq!(map.keys().collect::<Vec<_,>>(), vec![&"b", &"a", &"c",], "`VecListMap::insert` moved an existing key",);
+
+    assert!(map.move_to_back("b",), "`VecListMap::move_to_back` did not find the key",);
+    assert_eq!(map.shift_remove("a",), Some(2), "`VecListMap::shift_remove` returned the wrong value",);
+    assert_eq!(map.iter().collect::<Vec<_,>>(), vec![(&"c", &3,), (&"b", &4,),], "`VecListMap` incorrect order",);
+    assert_eq!(map.get("b",), Some(&4), "`VecListMap::get` returned the wrong value",);
+    assert_eq!(map.pop_front(), Some(("c", 3,)), "`VecListMap::pop_front` removed the wrong entry",);
+    assert!(!map.contains_key("c",), "`VecListMap::pop_front` did not remove the key",);
+
+    map.insert("d", 5,);
+    map.retain(|_, value,| *value % 2 == 1,);
+    assert_eq!(map.keys().collect::<Vec<_,>>(), vec![&"d",], "`VecListMap::retain` kept the wrong entries",);
+
+    //Keys are only stored once so they need not be `Clone`.
+    #[derive(PartialEq, Eq, Hash, Debug,)]
+    struct Key(i32,);
+
+    let mut map = VecListMap::new();
+
+    map.insert(Key(1,), 1,);
+    map.insert(Key(2,), 2,);
+    assert_eq!(map.shift_remove(&Key(1,),), Some(1), "`VecListMap::shift_remove` returned the wrong value",);
+    assert_eq!(map.pop_front(), Some((Key(2,), 2,)), "`VecListMap::pop_front` removed the wrong entry",);
+  }
+}
@@ -1,5 +1,5 @@
 
-use {VecList,};
+use {VecList, Alloc,};
 use std::mem::ManuallyDrop;
 
 /// A node in a double linked list.
@@ -10,6 +10,10 @@ pub struct Node<T,> {
   pub prev: Option<usize>,
   /// The index of the previous [`Node`].
   pub next: Option<usize>,
+  /// The number of times this [`Node`]s slot has been allocated or deallocated.
+  /// 
+  /// The generation is even while the slot is allocated and odd while it is empty.
+  pub generation: usize,
 }
 
 impl<T,> Node<T,> {
@@ -18,9 +22,10 @@ impl<T,> Node<T,> {
   /// # Params
   /// 
   /// value --- The value to populate the [`Node`] with.  
+  /// generation --- The generation of the [`Node`]s slot.  
   #[inline]
-  pub fn new(value: T,) -> Self {
-    Self { value: ManuallyDrop::new(value,), prev: None, next: None, }
+  pub fn new(value: T, generation: usize,) -> Self {
+    Self { value: ManuallyDrop::new(value,), prev: None, next: None, generation, }
   }
   /// Get the previous [`Node`].
   /// 
@@ -45,14 +50,14 @@ impl<T,> Node<T,> {
   /// # Params
   /// 
   /// list --- The [`VecList`] this [`Node`] is inside.
-  pub fn disconnect(&mut self, list: &mut VecList<T,>,) {
+  pub fn disconnect<A: Alloc + Clone,>(&mut self, list: &mut VecList<T, A,>,) {
     //Update the next pointer of the previous `Node`.
     if let Some(prev) = self.prev {
-      unsafe { &mut *list.node_mut(prev) }.next = self.next;
+      unsafe { (*list.node_mut(prev,)).next = self.next; }
     }
     //Update the previous pointer of the next `Node` and clear the current `Node`.
     if let Some(next) = self.next.take() {
-      unsafe { &mut *list.node_mut(next) }.prev = self.prev.take();
+      unsafe { (*list.node_mut(next,)).prev = self.prev.take(); }
     }
   }
   /// Pushes this [`Node`] into the head of a stack.
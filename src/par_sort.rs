@@ -0,0 +1,65 @@
+
+use {VecList, Alloc,};
+use rayon::slice::ParallelSliceMut;
+use std::cmp::Ordering;
+
+impl<T: Sync, A: Alloc + Clone + Sync,> VecList<T, A,> {
+  /// Sorts the [`VecList`] on the rayon thread pool by relinking its [`Node`]s.
+  /// 
+  /// See [`VecList::par_sort_by`].
+  #[inline]
+  pub fn par_sort(&mut self,)
+    where T: Ord, {
+    self.par_sort_by(T::cmp,)
+  }
+  /// Sorts the [`VecList`] using `cmp` on the rayon thread pool by relinking its
+  /// [`Node`]s.
+  /// 
+  /// The slots are sorted in parallel segments which are merged together and the
+  /// [`Node`]s are then relinked in sorted order in a single pass. The sort is stable
+  /// and no values are moved in memory.
+  /// 
+  /// # Params
+  /// 
+  /// cmp --- The comparison to sort by.  
+  pub fn par_sort_by<F,>(&mut self, cmp: F,)
+    where F: Fn(&T, &T,) -> Ordering + Sync, {
+    let (len, head, _,) = match self.ends {
+      Some(ends) => ends,
+      None => return,
+    };
+    //The slots in list order.
+    let mut slots = Vec::with_capacity(len.get(),);
+    let mut ptr = Some(head);
+
+    while let Some(node) = ptr {
+      slots.push(node,);
+      ptr = unsafe { (*self.node(node,)).next };
+    }
+
+    {
+      let list = &*self;
+
+      slots.par_sort_by(|&lhs, &rhs,| unsafe { cmp(list.value(lhs,), list.value(rhs,),) },);
+    }
+
+    unsafe { self.relink_slots(&slots, len,) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_par_sort() {
+    let mut list = (0..10_000).map(|i,| (i * 7_919 % 1_000, i,),).collect::<VecList<_,>>();
+    let mut sorted = list.to_vec();
+
+    sorted.sort_by_key(|value,| value.0,);
+    list.par_sort_by(|lhs, rhs,| lhs.0.cmp(&rhs.0,),);
+    assert_eq!(list.to_vec(), sorted, "`VecList::par_sort_by` sorted incorrectly or unstably",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), sorted.into_iter().rev().collect::<Vec<_,>>(),
+      "`VecList::par_sort_by` broke the `prev` links",);
+  }
+}
@@ -0,0 +1,289 @@
+
+use {VecList, Alloc, Global,};
+use std::{cell::RefCell, marker::PhantomData,};
+
+/// Hands out several [`PoolCursor`]s which can edit one [`VecList`] at the same time.
+/// 
+/// No two cursors may point at the same value; this is checked at runtime like a
+/// [`RefCell`] so each cursor can safely hand out a mutable reference to its value.
+/// The buffer is never grown while the pool exists so [`VecList::reserve`] should be
+/// called first if values will be inserted.
+/// 
+/// Created by [`VecList::cursors_mut`].
+pub struct CursorPool<'t, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The [`VecList`] being edited.
+  list: *mut VecList<T, A,>,
+  /// The [`Node`]s which cursors are pointing at.
+  occupied: RefCell<Vec<usize>>,
+  /// The mutable borrow of the [`VecList`].
+  _marker: PhantomData<&'t mut VecList<T, A,>>,
+}
+
+/// A cursor handed out by a [`CursorPool`].
+/// 
+/// Like [`CursorMut`] the cursor has a "ghost" position between the back and the front
+/// of the list which any number of cursors may rest on. Because other cursors can edit
+/// the list a [`PoolCursor`] does not track its index.
+pub struct PoolCursor<'p, 't: 'p, T: 't, A: 't + Alloc + Clone = Global,> {
+  /// The pool this cursor belongs to.
+  pool: &'p CursorPool<'t, T, A,>,
+  /// The index of the current [`Node`] or `None` at the ghost position.
+  current: Option<usize>,
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> VecList<T, A,> {
+  /// Returns a [`CursorPool`] which hands out several mutable cursors over the
+  /// [`VecList`].
+  #[inline]
+  pub fn cursors_mut(&'t mut self,) -> CursorPool<'t, T, A,> {
+    CursorPool { list: self, occupied: RefCell::new(Vec::new(),), _marker: PhantomData, }
+  }
+}
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> CursorPool<'t, T, A,> {
+  /// Marks the [`Node`] at `ptr` as pointed at by a cursor.
+  /// 
+  /// Returns `false` if another cursor is already pointing at it.
+  /// 
+  /// # Params
+  /// 
+  /// ptr --- The index of the [`Node`] or `None` for the ghost position.  
+  fn occupy(&self, ptr: Option<usize>,) -> bool {
+    let ptr = match ptr {
+      Some(ptr) => ptr,
+      None => return true,
+    };
+    let mut occupied = self.occupied.borrow_mut();
+
+    if occupied.contains(&ptr,) { false }
+    else { occupied.push(ptr,); true }
+  }
+  /// Marks the [`Node`] at `ptr` as no longer pointed at by a cursor.
+  /// 
+  /// # Params
+  /// 
+  /// ptr --- The index of the [`Node`] or `None` for the ghost position.  
+  fn release(&self, ptr: Option<usize>,) {
+    if let Some(ptr) = ptr { self.occupied.borrow_mut().retain(|&node| node != ptr,) }
+  }
+  /// Returns the number of values in the [`VecList`].
+  #[inline]
+  pub fn len(&self,) -> usize { unsafe { (*self.list).len() } }
+  /// Returns a cursor pointing at the value at `index` or `None` if another cursor is
+  /// already pointing at it.
+  /// 
+  /// If `index == self.len()` the cursor points at the ghost position.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the value to point at.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index > self.len()`.
+  pub fn try_cursor_at<'p,>(&'p self, index: usize,) -> Option<PoolCursor<'p, 't, T, A,>> {
+    let list = unsafe { &*self.list };
+
+    assert!(index <= list.len(), "`CursorPool::try_cursor_at` index out of range",);
+
+    let current = if index < list.len() { Some(list.ptr(index,)) } else { None };
+
+    if self.occupy(current,) { Some(PoolCursor { pool: self, current, }) }
+    else { None }
+  }
+  /// Returns a cursor pointing at the value at `index`.
+  /// 
+  /// If `index == self.len()` the cursor points at the ghost position.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The position of the value to point at.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `index > self.len()`.
+  /// * If another cursor is already pointing at the value.
+  #[inline]
+  pub fn cursor_at<'p,>(&'p self, index: usize,) -> PoolCursor<'p, 't, T, A,> {
+    self.try_cursor_at(index,).expect("`CursorPool::cursor_at` the value is already borrowed",)
+  }
+}
+
+impl<'p, 't: 'p, T: 't, A: 't + Alloc + Clone,> PoolCursor<'p, 't, T, A,> {
+  /// Returns a mutable reference to the value the cursor is pointing at.
+  #[inline]
+  pub fn current(&mut self,) -> Option<&mut T> {
+    let list = unsafe { &mut *self.pool.list };
+
+    self.current.map(move |ptr| unsafe { &mut *(*list.node_mut(ptr,)).value })
+  }
+  /// Moves the cursor to `next` if no other cursor is pointing at it.
+  /// 
+  /// # Params
+  /// 
+  /// next --- The index of the [`Node`] to move to.  
+  fn move_to(&mut self, next: Option<usize>,) -> bool {
+    if !self.pool.occupy(next,) { return false }
+
+    self.pool.release(self.current,);
+    self.current = next;
+
+    true
+  }
+  /// Moves the cursor to the next value unless another cursor is pointing at it.
+  /// 
+  /// Moving past the back of the [`VecList`] moves to the ghost position and moving
+  /// from the ghost position moves to the front of the [`VecList`].
+  /// 
+  /// Returns `false` if the cursor could not move.
+  pub fn try_move_next(&mut self,) -> bool {
+    let list = unsafe { &mut *self.pool.list };
+    let next = match self.current {
+      Some(ptr) => unsafe { (*list.node(ptr,)).next },
+      None => list.ends.map(|(_, head, _,)| head),
+    };
+
+    self.move_to(next,)
+  }
+  /// Moves the cursor to the previous value unless another cursor is pointing at it.
+  /// 
+  /// Moving past the front of the [`VecList`] moves to the ghost position and moving
+  /// from the ghost position moves to the back of the [`VecList`].
+  /// 
+  /// Returns `false` if the cursor could not move.
+  pub fn try_move_prev(&mut self,) -> bool {
+    let list = unsafe { &mut *self.pool.list };
+    let prev = match self.current {
+      Some(ptr) => unsafe { (*list.node(ptr,)).prev },
+      None => list.ends.map(|(_, _, tail,)| tail),
+    };
+
+    self.move_to(prev,)
+  }
+  /// Moves the cursor to the next value.
+  /// 
+  /// # Panics
+  /// 
+  /// * If another cursor is pointing at the next value.
+  #[inline]
+  pub fn move_next(&mut self,) {
+    assert!(self.try_move_next(), "`PoolCursor::move_next` the value is already borrowed",);
+  }
+  /// Moves the cursor to the previous value.
+  /// 
+  /// # Panics
+  /// 
+  /// * If another cursor is pointing at the previous value.
+  #[inline]
+  pub fn move_prev(&mut self,) {
+    assert!(self.try_move_prev(), "`PoolCursor::move_prev` the value is already borrowed",);
+  }
+  /// Inserts `value` before the [`Node`] at `next` if it fits in the buffer.
+  /// 
+  /// # Params
+  /// 
+  /// next --- The index of the [`Node`] to insert before or `None` to insert at the back.  
+  /// value --- The value to insert.  
+  fn insert(&mut self, next: Option<usize>, value: T,) -> Result<(), T> {
+    let list = unsafe { &mut *self.pool.list };
+
+    //Growing the buffer would move the values other cursors are borrowing.
+    if list.empty.is_none() && list.node_count >= list.capacity() { return Err(value) }
+
+    list.link_before(next, value,);
+
+    Ok(())
+  }
+  /// Inserts `value` before the current value.
+  /// 
+  /// At the ghost position `value` is inserted at the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to insert.  
+  /// 
+  /// # Errors
+  /// 
+  /// * If the buffer is full `value` is returned.
+  #[inline]
+  pub fn insert_before(&mut self, value: T,) -> Result<(), T> {
+    let current = self.current;
+
+    self.insert(current, value,)
+  }
+  /// Inserts `value` after the current value.
+  /// 
+  /// At the ghost position `value` is inserted at the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to insert.  
+  /// 
+  /// # Errors
+  /// 
+  /// * If the buffer is full `value` is returned.
+  pub fn insert_after(&mut self, value: T,) -> Result<(), T> {
+    let list = unsafe { &mut *self.pool.list };
+    let next = match self.current {
+      Some(ptr) => unsafe { (*list.node(ptr,)).next },
+      None => list.ends.map(|(_, head, _,)| head),
+    };
+
+    self.insert(next, value,)
+  }
+  /// Removes the current value and moves the cursor to the next value.
+  /// 
+  /// If another cursor is pointing at the next value this cursor moves to the ghost
+  /// position instead. Returns `None` at the ghost position.
+  pub fn remove_current(&mut self,) -> Option<T> {
+    let ptr = self.current?;
+    let list = unsafe { &mut *self.pool.list };
+    let next = unsafe { (*list.node(ptr,)).next };
+
+    self.pool.release(Some(ptr),);
+    self.current = if self.pool.occupy(next,) { next } else { None };
+
+    Some(list.unlink_node(ptr,))
+  }
+}
+
+impl<'p, 't: 'p, T: 't, A: 't + Alloc + Clone,> Drop for PoolCursor<'p, 't, T, A,> {
+  #[inline]
+  fn drop(&mut self,) { self.pool.release(self.current,) }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_pool() {
+    let mut list = (0..6).collect::<VecList<i32,>>();
+
+    {
+      let pool = list.cursors_mut();
+      let mut slow = pool.cursor_at(0,);
+      let mut fast = pool.cursor_at(1,);
+
+      assert!(pool.try_cursor_at(1,).is_none(), "`CursorPool::try_cursor_at` aliased a cursor",);
+      assert!(!fast.try_move_prev(), "`PoolCursor::try_move_prev` aliased a cursor",);
+
+      //Move the fast cursor two steps for every step of the slow cursor to find the middle.
+      loop {
+        fast.move_next();
+        if fast.current().is_none() { break }
+        fast.move_next();
+        if fast.current().is_none() { break }
+        slow.move_next();
+      }
+
+      *slow.current().expect("`PoolCursor::current` returned `None`") *= 10;
+      assert_eq!(fast.insert_before(6,), Err(6), "`PoolCursor::insert_before` grew the buffer",);
+      slow.move_next();
+      assert_eq!(slow.remove_current(), Some(3), "`PoolCursor::remove_current` removed the wrong value",);
+      assert_eq!(slow.insert_before(3,), Ok(()), "`PoolCursor::insert_before` did not reuse an empty `Node`",);
+    }
+
+    assert_eq!(list, [0, 1, 20, 3, 4, 5,], "`PoolCursor` edited the wrong values",);
+  }
+}
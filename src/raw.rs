@@ -0,0 +1,108 @@
+//! Unchecked access to the slots in the buffer of a [`VecList`].
+//! 
+//! These functions let specialised data structures be built on top of a [`VecList`]
+//! by addressing its slots directly. None of them check their arguments so callers must
+//! uphold the following invariants:
+//! 
+//! * A slot index must be less than the number of slots the [`VecList`] has ever used,
+//!   which is at most its capacity.
+//! * The slot must currently hold a value; slots whose value was removed may be reused
+//!   or hold a moved out value.
+//! * Slots are only stable until the [`VecList`] is reorganised; removing the value or
+//!   any operation documented as moving [`Node`]s invalidates the slot index.
+//! * References returned must not outlive the borrow of the [`VecList`] they came from
+//!   and mutable references must not alias.
+
+use {VecList, NodeId, Alloc,};
+
+/// Returns a reference to the value stored in `slot`.
+/// 
+/// # Params
+/// 
+/// list --- The [`VecList`] to read from.  
+/// slot --- The index of the slot in the buffer.  
+/// 
+/// # Safety
+/// 
+/// `slot` must hold a value in `list`.
+#[inline]
+pub unsafe fn raw_node<T, A: Alloc + Clone,>(list: &VecList<T, A,>, slot: usize,) -> &T { list.value(slot,) }
+
+/// Returns a mutable reference to the value stored in `slot`.
+/// 
+/// # Params
+/// 
+/// list --- The [`VecList`] to read from.  
+/// slot --- The index of the slot in the buffer.  
+/// 
+/// # Safety
+/// 
+/// `slot` must hold a value in `list`.
+#[inline]
+pub unsafe fn raw_node_mut<T, A: Alloc + Clone,>(list: &mut VecList<T, A,>, slot: usize,) -> &mut T {
+  &mut *(*list.node_mut(slot,)).value
+}
+
+/// Returns the slots before and after `slot` in the list.
+/// 
+/// # Params
+/// 
+/// list --- The [`VecList`] to read from.  
+/// slot --- The index of the slot in the buffer.  
+/// 
+/// # Safety
+/// 
+/// `slot` must hold a value in `list`.
+#[inline]
+pub unsafe fn raw_links<T, A: Alloc + Clone,>(list: &VecList<T, A,>, slot: usize,) -> (Option<usize>, Option<usize>,) {
+  let node = &*list.node(slot,);
+
+  (node.prev, node.next,)
+}
+
+/// Returns a reference to the value `id` refers to without checking that `id` is still
+/// valid.
+/// 
+/// # Params
+/// 
+/// list --- The [`VecList`] to read from.  
+/// id --- The handle to the value.  
+/// 
+/// # Safety
+/// 
+/// `id` must be a valid handle into `list`, see [`VecList::is_valid_handle`].
+#[inline]
+pub unsafe fn assume_valid_handle<T, A: Alloc + Clone,>(list: &VecList<T, A,>, id: NodeId,) -> &T {
+  list.value(id.slot(),)
+}
+
+/// Returns the slot `id` refers to.
+/// 
+/// # Params
+/// 
+/// id --- The handle to get the slot of.  
+#[inline]
+pub fn handle_slot(id: NodeId,) -> usize { id.slot() }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_raw() {
+    let mut list = vec![0, 1, 2,].into_iter().collect::<VecList<i32,>>();
+    let middle = list.front_handle().and_then(|front| list.next_handle(front,),)
+      .expect("`VecList::next_handle` returned `None`");
+    let slot = handle_slot(middle,);
+
+    unsafe {
+      assert_eq!(*raw_node(&list, slot,), 1, "`raw_node` read the wrong value",);
+      assert_eq!(*assume_valid_handle(&list, middle,), 1, "`assume_valid_handle` read the wrong value",);
+      assert_eq!(raw_links(&list, slot,), (Some(0), Some(2),), "`raw_links` read the wrong links",);
+
+      *raw_node_mut(&mut list, slot,) = 3;
+    }
+
+    assert_eq!(list.to_vec(), vec![0, 3, 2,], "`raw_node_mut` did not write the value",);
+  }
+}
@@ -9,15 +9,35 @@ macro_rules! alloc_err {
 }
 
 /// A heap allocated buffer of `T` aligned slots.
-pub struct RawVec<T,> {
+pub struct RawVec<T, A: Alloc = Global,> {
   /// The heap buffer.
   buf: *mut T,
   /// The capacity of the buffer.
   cap: usize,
+  /// The allocator the buffer is allocated from.
+  alloc: A,
 }
 
+//A `RawVec` uniquely owns its buffer.
+unsafe impl<T: Send, A: Alloc + Send,> Send for RawVec<T, A,> {}
+unsafe impl<T: Sync, A: Alloc + Sync,> Sync for RawVec<T, A,> {}
+
 impl<T,> RawVec<T,> {
-  /// Allocates a new [`RawVec`] with the passed capacity.
+  /// Allocates a new [`RawVec`] from the global allocator with the passed capacity.
+  /// 
+  /// # Params
+  /// 
+  /// cap --- The capacity of the new buffer.
+  /// 
+  /// # Panics
+  /// 
+  /// * If the allocation could not be made.
+  #[inline]
+  pub fn with_capacity(cap: usize,) -> Self { Self::with_capacity_in(cap, Global,) }
+}
+
+impl<T, A: Alloc,> RawVec<T, A,> {
+  /// Allocates a new [`RawVec`] from `alloc` with the passed capacity.
   /// 
   /// # Notes
   /// 
@@ -26,27 +46,31 @@ impl<T,> RawVec<T,> {
   /// 
   /// # Params
   /// 
-  /// cap --- The capacity of the new buffer.
+  /// cap --- The capacity of the new buffer.  
+  /// alloc --- The allocator to allocate the buffer from.  
   /// 
   /// # Panics
   /// 
   /// * If the allocation could not be made.
   #[inline]
-  pub fn with_capacity(mut cap: usize,) -> Self {
+  pub fn with_capacity_in(mut cap: usize, mut alloc: A,) -> Self {
     //Create the buffer.
     let buf = if mem::size_of::<T>() == 0 { cap = usize::max_value(); ptr::null_mut() }
       else if cap == 0 { ptr::null_mut() }
       //Allocate the array.
-      else { match Global.alloc_array::<T>(cap,) {
+      else { match alloc.alloc_array::<T>(cap,) {
         Ok(buf) => buf,
-        Err(e) => panic!(alloc_err!("RawVec::with_capacity", e,)),
+        Err(e) => panic!(alloc_err!("RawVec::with_capacity_in", e,)),
       }.as_ptr() };
 
-    Self { buf, cap, }
+    Self { buf, cap, alloc, }
   }
   /// Returns the capacity of the allocated buffer.
   #[inline]
   pub const fn cap(&self,) -> usize { self.cap }
+  /// Returns the allocator the buffer is allocated from.
+  #[inline]
+  pub const fn alloc(&self,) -> &A { &self.alloc }
   /// Reserves enough space for exactly `additional` more values.
   /// 
   /// # Params
@@ -64,10 +88,10 @@ impl<T,> RawVec<T,> {
         .expect("`RawVec::reserve_exact` additional overflowed usize");
 
       //Allocate a new `RawVec` if there was no allocation.
-      if self.cap() == 0 { *self = RawVec::with_capacity(new_cap,) }
+      if self.cap() == 0 { self.alloc_buf(new_cap,) }
       else {
         //Reallocate the buffer.
-        self.buf = match unsafe { Global.realloc_array(NonNull::new_unchecked(self.buf,), self.cap(), new_cap,) } {
+        self.buf = match unsafe { self.alloc.realloc_array(NonNull::new_unchecked(self.buf,), self.cap(), new_cap,) } {
           Ok(buf) => buf,
           Err(e) => panic!(alloc_err!("RawVec::with_capacity", e,)),
         }.as_ptr();
@@ -92,10 +116,10 @@ impl<T,> RawVec<T,> {
       let new_cap = usize::max(self.cap().saturating_mul(2,), used_cap,);
       
       //If there was not allocation just create an allocation.
-      if self.cap() == 0 { *self = RawVec::with_capacity(new_cap,) }
+      if self.cap() == 0 { self.alloc_buf(new_cap,) }
       else {
         //Reallocate the buffer.
-        self.buf = match unsafe { Global.realloc_array(NonNull::new_unchecked(self.buf,), self.cap(), new_cap,) } {
+        self.buf = match unsafe { self.alloc.realloc_array(NonNull::new_unchecked(self.buf,), self.cap(), new_cap,) } {
           Ok(buf) => buf,
           Err(e) => panic!(alloc_err!("RawVec::with_capacity", e,)),
         }.as_ptr();
@@ -107,14 +131,26 @@ impl<T,> RawVec<T,> {
   /// Gets the pointer to the start of the buffer.
   #[inline]
   pub const fn ptr(&self,) -> *mut T { self.buf }
+  /// Allocates the buffer of a [`RawVec`] with no allocation.
+  /// 
+  /// # Params
+  /// 
+  /// cap --- The capacity of the new buffer.  
+  fn alloc_buf(&mut self, cap: usize,) {
+    self.buf = match self.alloc.alloc_array::<T>(cap,) {
+      Ok(buf) => buf,
+      Err(e) => panic!(alloc_err!("RawVec::with_capacity", e,)),
+    }.as_ptr();
+    self.cap = cap;
+  }
 }
 
-impl<T,> Drop for RawVec<T,> {
+impl<T, A: Alloc,> Drop for RawVec<T, A,> {
   fn drop(&mut self,) {
     //Deallocate only if there was an allocation.
     if self.buf != ptr::null_mut() {
       //Deallocate the buffer.
-      if let Err(e) = unsafe { Global.dealloc_array(NonNull::new_unchecked(self.buf,), self.cap,) } {
+      if let Err(e) = unsafe { self.alloc.dealloc_array(NonNull::new_unchecked(self.buf,), self.cap,) } {
         panic!(alloc_err!("`RawVec::drop`", e,),)
       }
     }
@@ -142,5 +178,10 @@ mod tests {
     assert_eq!(vec.cap(), 20, "`RawVev::reserve` cap was not `20` when 1 was added with space left",);
     vec.reserve(20, 1,);
     assert_eq!(vec.cap(), 40, "`RawVev::reserve` cap was not `40` when 1 was added with no space left",);
+
+    let mut vec = RawVec::<i32,>::with_capacity_in(0, Global,);
+
+    vec.reserve(0, 1,);
+    assert_eq!(vec.cap(), 1, "`RawVec::reserve` cap was not `1` when 1 was added to an empty buffer",);
   }
 }
@@ -0,0 +1,163 @@
+
+use {VecList, Alloc,};
+use std::mem;
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Rotates the [`VecList`] so the first `n` values move to the back.
+  /// 
+  /// No values are moved; the ends are joined and the list is split again `n` values
+  /// in, walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to move to the back.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `n > self.len()`.
+  pub fn rotate_left(&mut self, n: usize,) {
+    assert!(n <= self.len(), "`VecList::rotate_left` index out of range",);
+
+    if n == 0 || n == self.len() { return }
+
+    let head = self.ptr(n,);
+
+    unsafe { self.rotate_to(head,) }
+  }
+  /// Rotates the [`VecList`] so the last `n` values move to the front.
+  /// 
+  /// No values are moved; the ends are joined and the list is split again `n` values
+  /// from the back, walking from whichever end is closer.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The number of values to move to the front.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `n > self.len()`.
+  pub fn rotate_right(&mut self, n: usize,) {
+    assert!(n <= self.len(), "`VecList::rotate_right` index out of range",);
+
+    if n == 0 || n == self.len() { return }
+
+    let head = self.ptr(self.len() - n,);
+
+    unsafe { self.rotate_to(head,) }
+  }
+  /// Reverses the order of the values in `O(n)` time.
+  /// 
+  /// No values are moved; the links of every [`Node`] and the ends are swapped.
+  pub fn reverse(&mut self,) {
+    let (len, head, tail,) = match self.ends {
+      Some(ends) => ends,
+      None => return,
+    };
+    let mut ptr = Some(head);
+
+    while let Some(node) = ptr {
+      let node = unsafe { &mut *self.node_mut(node,) };
+
+      ptr = node.next;
+      mem::swap(&mut node.prev, &mut node.next,);
+    }
+
+    self.ends = Some((len, tail, head,));
+  }
+  /// Swaps the values at positions `a` and `b`.
+  /// 
+  /// Both positions are found in a single walk which starts from whichever ends make it
+  /// shortest. The values are exchanged between the two [`Node`]s so handles keep
+  /// referring to the positions rather than the values.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The position of the first value.  
+  /// b --- The position of the second value.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `a >= self.len()` or `b >= self.len()`.
+  pub fn swap(&mut self, a: usize, b: usize,) {
+    let len = self.len();
+
+    assert!(a < len && b < len, "`VecList::swap` index out of range",);
+
+    if a == b { return }
+
+    let (lo, hi,) = if a < b { (a, b,) } else { (b, a,) };
+    let (_, head, tail,) = self.ends.expect("`VecList::swap` called on an empty `VecList`");
+    //The steps taken walking from the front, from the back or inwards from both ends.
+    let (front, back, both,) = (hi, len - 1 - lo, lo + (len - 1 - hi),);
+    let (lo, hi,) = if front <= back && front <= both {
+      let lo_ptr = self.forwards(head, lo,);
+
+      (lo_ptr, self.forwards(lo_ptr, hi - lo,),)
+    } else if back <= both {
+      let hi_ptr = self.backwards(tail, len - 1 - hi,);
+
+      (self.backwards(hi_ptr, hi - lo,), hi_ptr,)
+    } else { (self.forwards(head, lo,), self.backwards(tail, len - 1 - hi,),) };
+
+    unsafe {
+      let (lo, hi,) = (self.node_mut(lo,), self.node_mut(hi,),);
+
+      mem::swap(&mut *(*lo).value, &mut *(*hi).value,);
+    }
+  }
+  /// Joins the ends of the [`VecList`] and splits it again so `head` is at the front.
+  /// 
+  /// # Params
+  /// 
+  /// head --- The index of the [`Node`] to make the front; it must not be the front.  
+  unsafe fn rotate_to(&mut self, head: usize,) {
+    let (len, front, back,) = self.ends.expect("`VecList::rotate_to` called on an empty `VecList`");
+    let tail = (*self.node(head,)).prev();
+
+    (*self.node_mut(back,)).next = Some(front);
+    (*self.node_mut(front,)).prev = Some(back);
+    (*self.node_mut(tail,)).next = None;
+    (*self.node_mut(head,)).prev = None;
+
+    self.ends = Some((len, head, tail,));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_rotate() {
+    let mut list = (0..5).collect::<VecList<i32,>>();
+
+    list.rotate_left(2,);
+    assert_eq!(list, [2, 3, 4, 0, 1,], "`VecList::rotate_left` rotated incorrectly",);
+    list.rotate_right(1,);
+    assert_eq!(list, [1, 2, 3, 4, 0,], "`VecList::rotate_right` rotated incorrectly",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), vec![0, 4, 3, 2, 1,], "`VecList::rotate_right` broke the `prev` links",);
+    list.rotate_left(5,);
+    list.rotate_right(0,);
+    assert_eq!(list, [1, 2, 3, 4, 0,], "`VecList::rotate_*` changed the list by a full rotation",);
+  }
+  #[test]
+  fn test_reverse() {
+    let mut list = (0..5).collect::<VecList<i32,>>();
+
+    list.reverse();
+    assert_eq!(list, [4, 3, 2, 1, 0,], "`VecList::reverse` reversed incorrectly",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), vec![0, 1, 2, 3, 4,], "`VecList::reverse` broke the `prev` links",);
+  }
+  #[test]
+  fn test_swap() {
+    let mut list = vec![0, 4, 3, 2, 1,].into_iter().collect::<VecList<i32,>>();
+
+    list.swap(1, 3,);
+    list.swap(4, 3,);
+    list.swap(0, 4,);
+    list.swap(1, 0,);
+    list.swap(2, 2,);
+    assert_eq!(list, [2, 4, 3, 1, 0,], "`VecList::swap` swapped incorrectly",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), vec![0, 1, 3, 4, 2,], "`VecList::swap` broke the `prev` links",);
+  }
+}
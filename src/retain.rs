@@ -0,0 +1,94 @@
+
+use {VecList, Alloc,};
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Keeps only the values for which `f` returns `true`, walking in list order.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Returns `true` for the values to keep.  
+  #[inline]
+  pub fn retain<F,>(&mut self, mut f: F,)
+    where F: FnMut(&T,) -> bool, {
+    self.retain_mut(|value,| f(value,),)
+  }
+  /// Keeps only the values for which `f` returns `true`, walking in list order.
+  /// 
+  /// `f` may modify the values it is passed whether or not they are kept.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Returns `true` for the values to keep.  
+  pub fn retain_mut<F,>(&mut self, mut f: F,)
+    where F: FnMut(&mut T,) -> bool, {
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr {
+      let keep = unsafe {
+        let node = &mut *self.node_mut(node,);
+
+        ptr = node.next;
+        f(&mut node.value,)
+      };
+
+      if !keep { self.unlink_node(node,); }
+    }
+
+    self.after_removal();
+  }
+  /// Keeps only the values for which `f` returns `Ok(true)`, walking in list order.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Returns `true` for the values to keep.  
+  /// 
+  /// # Errors
+  /// 
+  /// * If `f` returns an error; the pass stops and the value which failed and every
+  ///   value after it are kept.
+  pub fn try_retain<E, F,>(&mut self, mut f: F,) -> Result<(), E>
+    where F: FnMut(&T,) -> Result<bool, E>, {
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+
+    while let Some(node) = ptr {
+      let keep = match f(unsafe { self.value(node,) },) {
+        Ok(keep) => keep,
+        Err(e) => { self.after_removal(); return Err(e) },
+      };
+
+      ptr = unsafe { (*self.node(node,)).next };
+
+      if !keep { self.unlink_node(node,); }
+    }
+
+    self.after_removal();
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_retain() {
+    let mut list = (0..6).collect::<VecList<i32,>>();
+
+    list.retain(|&value,| value != 3,);
+    assert_eq!(list, [0, 1, 2, 4, 5,], "`VecList::retain` kept the wrong values",);
+
+    list.retain_mut(|value,| { *value *= 2; *value % 4 == 0 },);
+    assert_eq!(list, [0, 4, 8,], "`VecList::retain_mut` kept the wrong values",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), vec![8, 4, 0,], "`VecList::retain_mut` broke the `prev` links",);
+  }
+  #[test]
+  fn test_try_retain() {
+    let mut list = (0..6).collect::<VecList<i32,>>();
+    let result = list.try_retain(|&value,| if value < 3 { Ok(value % 2 == 0) } else { Err(value) },);
+
+    assert_eq!(result, Err(3), "`VecList::try_retain` did not propagate the error",);
+    assert_eq!(list, [0, 2, 3, 4, 5,], "`VecList::try_retain` changed the unvisited values",);
+    assert_eq!(list.try_retain(|&value,| Ok::<_, ()>(value > 2),), Ok(()), "`VecList::try_retain` failed",);
+    assert_eq!(list, [3, 4, 5,], "`VecList::try_retain` kept the wrong values",);
+  }
+}
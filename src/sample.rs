@@ -0,0 +1,97 @@
+
+use {VecList, Alloc,};
+use rand::Rng;
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Chooses the indexes of `k` [`Node`]s uniformly at random in a single pass using
+  /// reservoir sampling.
+  /// 
+  /// # Params
+  /// 
+  /// rng --- The source of randomness.  
+  /// k --- The number of [`Node`]s to choose.  
+  fn sample_ptrs<R,>(&self, rng: &mut R, k: usize,) -> Vec<usize>
+    where R: Rng + ?Sized, {
+    let mut reservoir = Vec::with_capacity(usize::min(k, self.len(),),);
+    let mut ptr = self.ends.map(|(_, head, _,)| head);
+    let mut index = 0;
+
+    while let Some(node) = ptr {
+      //Fill the reservoir first.
+      if index < k { reservoir.push(node,) }
+      //Replace a sampled `Node` with decreasing probability.
+      else {
+        let replace = rng.gen_range(0, index + 1,);
+
+        if replace < k { reservoir[replace] = node }
+      }
+
+      ptr = unsafe { (*self.node(node,)).next };
+      index += 1;
+    }
+
+    reservoir
+  }
+  /// Returns references to `k` values chosen uniformly at random from the [`VecList`].
+  /// 
+  /// If the [`VecList`] has `k` or fewer values all of them are returned. The values
+  /// are not returned in any particular order.
+  /// 
+  /// # Params
+  /// 
+  /// rng --- The source of randomness.  
+  /// k --- The number of values to sample.  
+  pub fn sample<R,>(&self, rng: &mut R, k: usize,) -> Vec<&T>
+    where R: Rng + ?Sized, {
+    self.sample_ptrs(rng, k,).into_iter()
+      .map(|ptr| unsafe { self.value(ptr,) })
+      .collect()
+  }
+  /// Removes `k` values chosen uniformly at random from the [`VecList`] and returns
+  /// them.
+  /// 
+  /// If the [`VecList`] has `k` or fewer values all of them are removed. The values
+  /// are not returned in any particular order.
+  /// 
+  /// # Params
+  /// 
+  /// rng --- The source of randomness.  
+  /// k --- The number of values to sample.  
+  pub fn sample_remove<R,>(&mut self, rng: &mut R, k: usize,) -> Vec<T>
+    where R: Rng + ?Sized, {
+    let values = self.sample_ptrs(rng, k,).into_iter()
+      .map(|ptr| self.unlink_node(ptr,))
+      .collect();
+
+    self.after_removal(); values
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::{SeedableRng, prng::XorShiftRng,};
+
+  #[test]
+  fn test_sample() {
+    let mut rng = XorShiftRng::from_seed([7; 16],);
+    let mut list = (0..10).collect::<VecList<i32,>>();
+
+    assert_eq!(list.sample(&mut rng, 20,).len(), 10, "`VecList::sample` did not return every value",);
+
+    let mut sample = list.sample(&mut rng, 4,).into_iter().cloned().collect::<Vec<_,>>();
+
+    sample.sort();
+    sample.dedup();
+    assert_eq!(sample.len(), 4, "`VecList::sample` returned the wrong number of distinct values",);
+
+    let removed = list.sample_remove(&mut rng, 3,);
+
+    assert_eq!(removed.len(), 3, "`VecList::sample_remove` returned the wrong number of values",);
+    assert_eq!(list.len(), 7, "`VecList::sample_remove` did not remove the values",);
+
+    list.set_compaction_policy(::CompactionPolicy::FreePercent(0,),);
+    list.sample_remove(&mut rng, 2,);
+    assert_eq!(list.free_slots(), 0, "`VecList::sample_remove` ignored the compaction policy",);
+  }
+}
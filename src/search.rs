@@ -0,0 +1,223 @@
+
+use {VecList, Alloc,};
+use std::cmp::Ordering;
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Returns `true` if the [`VecList`] contains a value equal to `value`.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to search for.  
+  #[inline]
+  pub fn contains(&self, value: &T,) -> bool
+    where T: PartialEq, {
+    self.find_ptr(|probe,| probe == value,).is_some()
+  }
+  /// Returns the index of the first value which satisfies `pred`.
+  /// 
+  /// The search walks forwards from the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  #[inline]
+  pub fn position<P,>(&self, pred: P,) -> Option<usize>
+    where P: FnMut(&T,) -> bool, {
+    self.find_ptr(pred,).map(|(index, _,)| index)
+  }
+  /// Removes and returns the first value which satisfies `pred`.
+  /// 
+  /// The search walks forwards from the front of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn remove_first_where<P,>(&mut self, pred: P,) -> Option<T>
+    where P: FnMut(&T,) -> bool, {
+    let (_, node,) = self.find_ptr(pred,)?;
+    let value = self.unlink_node(node,);
+
+    self.after_removal(); Some(value)
+  }
+  /// Removes and returns the last value which satisfies `pred`.
+  /// 
+  /// The search walks backwards from the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn remove_last_where<P,>(&mut self, pred: P,) -> Option<T>
+    where P: FnMut(&T,) -> bool, {
+    let (_, node,) = self.rfind_ptr(pred,)?;
+    let value = self.unlink_node(node,);
+
+    self.after_removal(); Some(value)
+  }
+  /// Returns the index and the [`Node`] of the first value which satisfies `pred`.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub(crate) fn find_ptr<P,>(&self, mut pred: P,) -> Option<(usize, usize,)>
+    where P: FnMut(&T,) -> bool, {
+    let (mut index, mut ptr,) = (0, self.ends.map(|(_, head, _,)| head),);
+
+    while let Some(node) = ptr {
+      let node_ref = unsafe { &*self.node(node,) };
+
+      if pred(&node_ref.value,) { return Some((index, node,)) }
+
+      index += 1;
+      ptr = node_ref.next;
+    }
+
+    None
+  }
+  /// Returns the index of the last value which satisfies `pred`.
+  /// 
+  /// The search walks backwards from the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  #[inline]
+  pub fn rposition<P,>(&self, pred: P,) -> Option<usize>
+    where P: FnMut(&T,) -> bool, {
+    self.rfind_ptr(pred,).map(|(index, _,)| index)
+  }
+  /// Returns the index and the [`Node`] of the last value which satisfies `pred`.
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub(crate) fn rfind_ptr<P,>(&self, mut pred: P,) -> Option<(usize, usize,)>
+    where P: FnMut(&T,) -> bool, {
+    let (mut index, mut ptr,) = (self.len(), self.ends.map(|(_, _, tail,)| tail),);
+
+    while let Some(node) = ptr {
+      let node_ref = unsafe { &*self.node(node,) };
+
+      index -= 1;
+      if pred(&node_ref.value,) { return Some((index, node,)) }
+
+      ptr = node_ref.prev;
+    }
+
+    None
+  }
+  /// Returns a reference to the last value which satisfies `pred`.
+  /// 
+  /// The search walks backwards from the back of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// pred --- The predicate to search with.  
+  pub fn find_last<P,>(&self, mut pred: P,) -> Option<&T>
+    where P: FnMut(&T,) -> bool, {
+    let mut ptr = self.ends.map(|(_, _, tail,)| tail);
+
+    while let Some(node) = ptr {
+      let node = unsafe { &*self.node(node,) };
+
+      if pred(&node.value,) { return Some(&node.value) }
+
+      ptr = node.prev;
+    }
+
+    None
+  }
+  /// Searches a sorted [`VecList`] for `value`.
+  /// 
+  /// See [`VecList::binary_search_by`].
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to search for.  
+  #[inline]
+  pub fn binary_search(&self, value: &T,) -> Result<usize, usize>
+    where T: Ord, {
+    self.binary_search_by(|probe,| probe.cmp(value,),)
+  }
+  /// Searches a sorted [`VecList`] for a value whose key `f` extracts matches `key`.
+  /// 
+  /// See [`VecList::binary_search_by`].
+  /// 
+  /// # Params
+  /// 
+  /// key --- The key to search for.  
+  /// f --- Extracts the key the [`VecList`] is sorted by.  
+  #[inline]
+  pub fn binary_search_by_key<K, F,>(&self, key: &K, mut f: F,) -> Result<usize, usize>
+    where K: Ord, F: FnMut(&T,) -> K, {
+    self.binary_search_by(|probe,| f(probe,).cmp(key,),)
+  }
+  /// Searches a sorted [`VecList`] using `f`, which returns the order of each probed
+  /// value relative to the target.
+  /// 
+  /// Returns `Ok` with the index of the first matching value or `Err` with the index
+  /// the target could be inserted at to keep the [`VecList`] sorted. The probes walk
+  /// the list as described in [`VecList::partition_point`].
+  /// 
+  /// If the [`VecList`] is not sorted the result is unspecified.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Compares a probed value to the target.  
+  pub fn binary_search_by<F,>(&self, mut f: F,) -> Result<usize, usize>
+    where F: FnMut(&T,) -> Ordering, {
+    let (index, id,) = self.partition_point(|probe,| f(probe,) == Ordering::Less,);
+
+    match id.and_then(|id,| self.get_by_handle(id,),) {
+      Some(value) if f(value,) == Ordering::Equal => Ok(index),
+      _ => Err(index),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_contains() {
+    let list = vec![1, 2, 3, 2, 1,].into_iter().collect::<VecList<i32,>>();
+
+    assert!(list.contains(&3,), "`VecList::contains` missed a value",);
+    assert!(!list.contains(&4,), "`VecList::contains` found a missing value",);
+    assert_eq!(list.position(|&x| x == 2,), Some(1), "`VecList::position` found the wrong value",);
+    assert_eq!(list.position(|&x| x == 4,), None, "`VecList::position` found a missing value",);
+  }
+  #[test]
+  fn test_rposition() {
+    let list = vec![1, 2, 3, 2, 1,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list.rposition(|&x| x == 2,), Some(3), "`VecList::rposition` found the wrong value",);
+    assert_eq!(list.rposition(|&x| x == 4,), None, "`VecList::rposition` found a missing value",);
+    assert_eq!(list.find_last(|&x| x < 3,), Some(&1), "`VecList::find_last` found the wrong value",);
+    assert_eq!(list.find_last(|&x| x > 3,), None, "`VecList::find_last` found a missing value",);
+  }
+  #[test]
+  fn test_remove_where() {
+    let mut list = vec![1, 2, 3, 2, 1,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list.remove_first_where(|&x| x < 3,), Some(1), "`VecList::remove_first_where` removed the wrong value",);
+    assert_eq!(list.remove_last_where(|&x| x < 3,), Some(1), "`VecList::remove_last_where` removed the wrong value",);
+    assert_eq!(list.remove_last_where(|&x| x > 3,), None, "`VecList::remove_last_where` removed a missing value",);
+    assert_eq!(list, [2, 3, 2,], "`VecList::remove_*_where` removed the wrong values",);
+    list.set_compaction_policy(::CompactionPolicy::FreePercent(0,),);
+    list.remove_first_where(|&x| x == 3,);
+    assert_eq!(list.free_slots(), 0, "`VecList::remove_first_where` ignored the compaction policy",);
+  }
+  #[test]
+  fn test_binary_search() {
+    let list = vec![1, 3, 3, 5, 8,].into_iter().collect::<VecList<i32,>>();
+
+    assert_eq!(list.binary_search(&3,).map(|index,| list[index],), Ok(3), "`VecList::binary_search` found the wrong value",);
+    assert_eq!(list.binary_search(&0,), Err(0), "`VecList::binary_search` incorrect insertion point",);
+    assert_eq!(list.binary_search(&4,), Err(3), "`VecList::binary_search` incorrect insertion point",);
+    assert_eq!(list.binary_search(&9,), Err(5), "`VecList::binary_search` incorrect insertion point",);
+    assert_eq!(list.binary_search_by_key(&4, |&x| x / 2,), Ok(4), "`VecList::binary_search_by_key` found the wrong value",);
+    assert_eq!(list.binary_search_by(|x,| x.cmp(&5,),), Ok(3), "`VecList::binary_search_by` found the wrong value",);
+    assert_eq!(VecList::<i32>::new().binary_search(&1,), Err(0), "`VecList::binary_search` failed when empty",);
+  }
+}
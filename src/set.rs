@@ -0,0 +1,133 @@
+
+use {VecListMap,};
+use std::{
+  hash::Hash, borrow::Borrow, fmt,
+  iter::{FromIterator, Extend,},
+};
+
+/// A set which remembers the order its values were inserted in.
+/// 
+/// Built on a [`VecListMap`] so values can be found and removed without walking the
+/// list while iteration follows insertion order.
+pub struct VecListSet<T,> {
+  /// The values in insertion order.
+  map: VecListMap<T, (),>,
+}
+
+impl<T: Hash + Eq,> VecListSet<T,> {
+  /// Constructs a new empty [`VecListSet`].
+  #[inline]
+  pub fn new() -> Self { Self::with_capacity(0,) }
+  /// Constructs a new empty [`VecListSet`] with space for `capacity` values.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of values to allocate space for.  
+  #[inline]
+  pub fn with_capacity(capacity: usize,) -> Self {
+    Self { map: VecListMap::with_capacity(capacity,), }
+  }
+  /// Returns the number of values.
+  #[inline]
+  pub fn len(&self,) -> usize { self.map.len() }
+  /// Returns `true` if there are no values.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.map.is_empty() }
+  /// Returns `true` if the set contains `value`.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to look up.  
+  #[inline]
+  pub fn contains<Q,>(&self, value: &Q,) -> bool
+    where T: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    self.map.contains_key(value,)
+  }
+  /// Adds `value` at the back of the set.
+  /// 
+  /// Returns `false` and leaves the set unchanged if it already contains `value`.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to add.  
+  #[inline]
+  pub fn insert(&mut self, value: T,) -> bool {
+    if self.contains(&value,) { return false }
+
+    self.map.insert(value, (),);
+
+    true
+  }
+  /// Removes `value` keeping the order of the other values.
+  /// 
+  /// Returns `false` if the set did not contain `value`.
+  /// 
+  /// # Params
+  /// 
+  /// value --- The value to remove.  
+  #[inline]
+  pub fn remove<Q,>(&mut self, value: &Q,) -> bool
+    where T: Borrow<Q>, Q: Hash + Eq + ?Sized, {
+    self.map.shift_remove(value,).is_some()
+  }
+  /// Keeps only the values for which `f` returns `true`, walking in insertion order.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Returns `true` for the values to keep.  
+  #[inline]
+  pub fn retain<F,>(&mut self, mut f: F,)
+    where F: FnMut(&T,) -> bool, {
+    self.map.retain(|value, _,| f(value,),)
+  }
+  /// Returns an iterator over the values in insertion order.
+  #[inline]
+  pub fn iter(&self,) -> impl DoubleEndedIterator<Item = &T> { self.map.keys() }
+}
+
+impl<T: Hash + Eq,> Default for VecListSet<T,> {
+  #[inline]
+  fn default() -> Self { Self::new() }
+}
+
+impl<T: Hash + Eq,> FromIterator<T> for VecListSet<T,> {
+  #[inline]
+  fn from_iter<I,>(iter: I,) -> Self
+    where I: IntoIterator<Item = T>, {
+    let mut set = Self::new();
+
+    set.extend(iter,); set
+  }
+}
+
+impl<T: Hash + Eq,> Extend<T> for VecListSet<T,> {
+  #[inline]
+  fn extend<I,>(&mut self, iter: I,)
+    where I: IntoIterator<Item = T>, {
+    for value in iter { self.insert(value,); }
+  }
+}
+
+impl<T: fmt::Debug,> fmt::Debug for VecListSet<T,> {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { self.map.fmt_keys(fmt,) }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_set() {
+    let mut set = vec![3, 1, 3, 2, 1,].into_iter().collect::<VecListSet<i32,>>();
+
+    assert_eq!(set.iter().collect::<Vec<_,>>(), vec![&3, &1, &2,], "`VecListSet::insert` incorrect order",);
+    assert!(!set.insert(1,), "`VecListSet::insert` added a duplicate",);
+    assert!(set.remove(&3,), "`VecListSet::remove` did not find the value",);
+    assert!(!set.contains(&3,), "`VecListSet::remove` did not remove the value",);
+
+    set.extend(vec![4, 5,],);
+    set.retain(|value,| value % 2 == 0,);
+    assert_eq!(format!("{:?}", set,), "{2, 4}", "`VecListSet::retain` kept the wrong values",);
+  }
+}
@@ -0,0 +1,691 @@
+
+use {VecList, IterMut, NonZeroUsize, Alloc,};
+use std::{mem, cmp::Ordering,};
+
+/// Relinks every allocated [`Node`] of a [`VecList`] in buffer order when dropped.
+/// 
+/// The relinking sorts hold one while their `next` links are split into runs so a
+/// comparison which panics leaves every value in the [`VecList`] in an unspecified
+/// order; it is forgotten once the links are restored.
+struct Relink<'t, T: 't, A: 't + Alloc + Clone,>(&'t mut VecList<T, A,>,);
+
+impl<'t, T: 't, A: 't + Alloc + Clone,> Drop for Relink<'t, T, A,> {
+  fn drop(&mut self,) {
+    let list = &mut *self.0;
+    let (mut head, mut tail, mut len,) = (None, None::<usize>, 0,);
+
+    for ptr in 0..list.node_count {
+      unsafe {
+        //Only allocated slots have an even generation.
+        if (*list.node(ptr,)).generation % 2 != 0 { continue }
+
+        match tail {
+          Some(tail) => list.node_append(tail, ptr,),
+          None => { (*list.node_mut(ptr,)).prev = None; head = Some(ptr) },
+        }
+        (*list.node_mut(ptr,)).next = None;
+      }
+
+      tail = Some(ptr);
+      len += 1;
+    }
+
+    list.ends = match (NonZeroUsize::new(len,), head, tail,) {
+      (Some(len), Some(head), Some(tail),) => Some((len, head, tail,)),
+      _ => None,
+    };
+  }
+}
+
+/// A run of sorted [`Node`]s linked through their `next` pointers.
+#[derive(Clone, Copy,)]
+struct Run {
+  /// The index of the first [`Node`] in the run.
+  head: usize,
+  /// The index of the last [`Node`] in the run.
+  tail: usize,
+  /// The number of [`Node`]s in the run.
+  len: usize,
+}
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Sorts the [`VecList`] by relinking its [`Node`]s.
+  /// 
+  /// The sort is stable and no values are moved in memory, see [`VecList::sort_by`].
+  #[inline]
+  pub fn sort(&mut self,)
+    where T: Ord, {
+    self.sort_by(T::cmp,)
+  }
+  /// Sorts the [`VecList`] using `cmp` by relinking its [`Node`]s.
+  /// 
+  /// The sort is stable and no values are moved in memory. Runs of values which are
+  /// already in ascending or descending order are detected and merged so sorting a
+  /// nearly sorted [`VecList`] approaches `O(n)`.
+  /// 
+  /// If `cmp` panics the values are kept in an unspecified order.
+  /// 
+  /// # Params
+  /// 
+  /// cmp --- The comparison to sort by.  
+  #[inline]
+  pub fn sort_by<F,>(&mut self, mut cmp: F,)
+    where F: FnMut(&T, &T,) -> Ordering, {
+    self.sort_slots_by(|list, lhs, rhs,| unsafe { cmp(list.value(lhs,), list.value(rhs,),) },)
+  }
+  /// Sorts the [`VecList`] by the key `f` extracts from each value by relinking its
+  /// [`Node`]s.
+  /// 
+  /// The sort is stable and `f` is called exactly once for each value; the keys are
+  /// cached beside their slots in list order and sorted before the [`Node`]s are
+  /// relinked. If `f` or comparing the keys panics the [`VecList`] is left unchanged.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Extracts the key to sort by.  
+  pub fn sort_by_cached_key<K, F,>(&mut self, mut f: F,)
+    where K: Ord, F: FnMut(&T,) -> K, {
+    let (len, head, _,) = match self.ends {
+      Some(ends) => ends,
+      None => return,
+    };
+    //The keys and their slots in list order.
+    let mut keys = Vec::with_capacity(len.get(),);
+    let mut ptr = Some(head);
+
+    while let Some(node) = ptr {
+      let node_ref = unsafe { &*self.node(node,) };
+
+      keys.push((f(&*node_ref.value,), node,),);
+      ptr = node_ref.next;
+    }
+
+    keys.sort_by(|lhs, rhs,| lhs.0.cmp(&rhs.0,),);
+
+    let slots = keys.into_iter().map(|(_, slot,)| slot,).collect::<Vec<_,>>();
+
+    unsafe { self.relink_slots(&slots, len,) }
+  }
+  /// Sorts the [`VecList`] by moving the values into sorted order at the start of the
+  /// buffer, see [`VecList::sort_values_by`].
+  #[inline]
+  pub fn sort_values(&mut self,)
+    where T: Ord, {
+    self.sort_values_by(T::cmp,)
+  }
+  /// Sorts the [`VecList`] using `cmp` by moving the values rather than relinking
+  /// [`Node`]s.
+  /// 
+  /// For small values sorting them contiguously is faster than chasing links and the
+  /// buffer is defragmented as a side effect, see [`VecList::compact_with`]. The sort is
+  /// stable but the values are moved out of the buffer to be sorted, invalidating every
+  /// handle into the [`VecList`]. If `cmp` panics the values are kept in an unspecified
+  /// order.
+  /// 
+  /// # Params
+  /// 
+  /// cmp --- The comparison to sort by.  
+  #[inline]
+  pub fn sort_values_by<F,>(&mut self, cmp: F,)
+    where F: FnMut(&T, &T,) -> Ordering, {
+    self.with_contiguous(|values,| values.sort_by(cmp,),)
+  }
+  /// Sorts the [`VecList`] using `cmp` to compare the values in two slots.
+  /// 
+  /// # Params
+  /// 
+  /// cmp --- The comparison to sort by.  
+  fn sort_slots_by<F,>(&mut self, mut cmp: F,)
+    where F: FnMut(&Self, usize, usize,) -> Ordering, {
+    let (len, head, _,) = match self.ends {
+      Some(ends) => ends,
+      None => return,
+    };
+    //The stack of runs waiting to be merged.
+    let mut runs = Vec::<Run>::new();
+    let mut next = Some(head);
+    let guard = Relink(&mut *self,);
+
+    //Split the list into runs and merge them as they are found.
+    while let Some(start) = next {
+      let (run, rest,) = unsafe { guard.0.take_run(start, &mut cmp,) };
+
+      next = rest;
+      runs.push(run,);
+      guard.0.collapse_runs(&mut runs, &mut cmp,);
+    }
+    //Merge any remaining runs.
+    while runs.len() > 1 {
+      let at = runs.len() - 2;
+
+      guard.0.merge_runs_at(&mut runs, at, &mut cmp,);
+    }
+
+    unsafe { guard.0.restore_links(runs[0], len,) }
+    mem::forget(guard,);
+  }
+  /// Sorts the [`VecList`] by the key `f` extracts from each value by relinking its
+  /// [`Node`]s.
+  /// 
+  /// The sort is stable and `f` is called `O(n log n)` times.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Extracts the key to sort by.  
+  #[inline]
+  pub fn sort_by_key<K, F,>(&mut self, mut f: F,)
+    where K: Ord, F: FnMut(&T,) -> K, {
+    self.sort_by(|lhs, rhs,| f(lhs,).cmp(&f(rhs,),),)
+  }
+  /// Returns `true` if the values are in ascending order.
+  /// 
+  /// See [`VecList::is_sorted_by`].
+  #[inline]
+  pub fn is_sorted(&self,) -> bool
+    where T: PartialOrd, {
+    self.is_sorted_by(|lhs, rhs,| lhs.partial_cmp(rhs,).unwrap_or(Ordering::Greater,),)
+  }
+  /// Returns `true` if the values are in the order the keys `f` extracts are sorted in.
+  /// 
+  /// # Params
+  /// 
+  /// f --- Extracts the key the values should be sorted by.  
+  #[inline]
+  pub fn is_sorted_by_key<K, F,>(&self, mut f: F,) -> bool
+    where K: PartialOrd, F: FnMut(&T,) -> K, {
+    self.is_sorted_by(|lhs, rhs,| f(lhs,).partial_cmp(&f(rhs,),).unwrap_or(Ordering::Greater,),)
+  }
+  /// Returns `true` if no value compares greater than the value after it using `cmp`.
+  /// 
+  /// This walks the [`VecList`] once and is intended for checking the preconditions of
+  /// the methods which require a sorted [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// cmp --- The comparison the values should be sorted by.  
+  pub fn is_sorted_by<F,>(&self, mut cmp: F,) -> bool
+    where F: FnMut(&T, &T,) -> Ordering, {
+    let mut iter = self.iter();
+    let mut prev = match iter.next() {
+      Some(prev) => prev,
+      None => return true,
+    };
+
+    for value in iter {
+      if cmp(prev, value,) == Ordering::Greater { return false }
+
+      prev = value;
+    }
+
+    true
+  }
+  /// Merges the sorted values of `other` into this sorted [`VecList`].
+  /// 
+  /// See [`VecList::merge_by`].
+  /// 
+  /// # Params
+  /// 
+  /// other --- The sorted [`VecList`] to merge in.  
+  #[inline]
+  pub fn merge(&mut self, other: Self,)
+    where T: Ord, {
+    self.merge_by(other, T::cmp,)
+  }
+  /// Merges the values of `other` into this [`VecList`] where both are sorted by `cmp`.
+  /// 
+  /// The values of `other` are moved into the buffer of this [`VecList`] and the two
+  /// lists are interleaved by relinking in `O(n + m)` time. The merge is stable; values
+  /// already in this [`VecList`] come before equal values from `other`.
+  /// 
+  /// If either [`VecList`] is not sorted by `cmp` the order of the result is unspecified.
+  /// If `cmp` panics the values of both lists are kept in an unspecified order.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The sorted [`VecList`] to merge in.  
+  /// cmp --- The comparison both lists are sorted by.  
+  pub fn merge_by<F,>(&mut self, mut other: Self, mut cmp: F,)
+    where F: FnMut(&T, &T,) -> Ordering, {
+    let (len, head, tail,) = match self.ends {
+      Some(ends) => ends,
+      None => return self.append(&mut other,),
+    };
+    let (start, end, count,) = match self.adopt_nodes(&mut other,) {
+      Some(chain) => chain,
+      None => return,
+    };
+    let left = Run { head, tail, len: len.get(), };
+    let right = Run { head: start, tail: end, len: count, };
+    let mut cmp = |list: &Self, lhs, rhs,| unsafe { cmp(list.value(lhs,), list.value(rhs,),) };
+
+    let guard = Relink(&mut *self,);
+
+    unsafe {
+      let run = guard.0.merge_runs(left, right, &mut cmp,);
+
+      guard.0.restore_links(run, NonZeroUsize::new_unchecked(len.get() + count,),);
+    }
+    mem::forget(guard,);
+  }
+  /// Partitions the [`VecList`] so that the value at index `n` is where it would be if
+  /// the [`VecList`] was sorted.
+  /// 
+  /// Every value before index `n` is less than or equal to it and every value after
+  /// index `n` is greater than or equal to it. The partitioning is done by relinking
+  /// [`Node`]s so no values are moved in memory and takes `O(n)` time on average.
+  /// If `cmp` panics the values are kept in an unspecified order.
+  /// 
+  /// Like [`slice::select_nth_unstable_by`] returns the values before index `n`, the
+  /// value at index `n` and the values after index `n`.
+  /// 
+  /// # Params
+  /// 
+  /// n --- The index of the value to select.  
+  /// cmp --- The comparison to select by.  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `n >= self.len()`.
+  pub fn select_nth_unstable_by<'t, F,>(&'t mut self, mut n: usize, mut cmp: F,) -> (IterMut<'t, T, A,>, &'t mut T, IterMut<'t, T, A,>,)
+    where F: FnMut(&T, &T,) -> Ordering, {
+    assert!(n < self.len(), "`VecList::select_nth_unstable_by` index out of range",);
+
+    let index = n;
+
+    let (len, head, tail,) = self.ends.expect("`VecList::select_nth_unstable_by` called on an empty `VecList`");
+    //The values known to come before the segment being partitioned.
+    let mut before = None;
+    //The segment being partitioned.
+    let mut segment = Run { head, tail, len: len.get(), };
+    //The values known to come after the segment being partitioned.
+    let mut after = None;
+
+    let guard = Relink(&mut *self,);
+    let pivot = unsafe { loop {
+      //Choose the middle value as the pivot.
+      let pivot = guard.0.forwards(segment.head, segment.len / 2,);
+      let (mut less, mut equal, mut greater,) = (None, None, None,);
+      let mut ptr = Some(segment.head);
+
+      //Split the segment around the pivot.
+      (*guard.0.node_mut(segment.tail,)).next = None;
+      while let Some(node) = ptr {
+        ptr = (*guard.0.node(node,)).next;
+
+        let part = match cmp(guard.0.value(node,), guard.0.value(pivot,),) {
+          Ordering::Less => &mut less,
+          Ordering::Equal => &mut equal,
+          Ordering::Greater => &mut greater,
+        };
+
+        *part = guard.0.join_runs(*part, Some(Run { head: node, tail: node, len: 1, }),);
+      }
+
+      let less_len = less.map_or(0, |run: Run| run.len,);
+      let equal_run = equal.expect("`VecList::select_nth_unstable_by` the pivot was not partitioned");
+
+      //Narrow down to the segment containing index `n`.
+      if n < less_len {
+        let greater = guard.0.join_runs(equal, greater,);
+
+        after = guard.0.join_runs(greater, after,);
+        segment = less.expect("`VecList::select_nth_unstable_by` the lesser partition is empty");
+      } else if n < less_len + equal_run.len {
+        let nth = guard.0.forwards(equal_run.head, n - less_len,);
+
+        let greater = guard.0.join_runs(equal, greater,);
+
+        before = guard.0.join_runs(before, less,);
+        after = guard.0.join_runs(greater, after,);
+        break nth
+      } else {
+        let less = guard.0.join_runs(before, less,);
+
+        before = guard.0.join_runs(less, equal,);
+        segment = greater.expect("`VecList::select_nth_unstable_by` the greater partition is empty");
+        n -= less_len + equal_run.len;
+      }
+    } };
+
+    unsafe {
+      let list = guard.0.join_runs(before, after,)
+        .expect("`VecList::select_nth_unstable_by` the list is empty");
+
+      guard.0.restore_links(list, len,);
+      mem::forget(guard,);
+
+      let (prev, next,) = { let node = &*self.node(pivot,); (node.prev, node.next,) };
+      let (_, head, tail,) = self.ends.expect("`VecList::select_nth_unstable_by` the list is empty");
+      let list = self as *mut Self;
+
+      (
+        IterMut::from_range(list, prev.map(|prev| (head, prev,),), index,),
+        &mut *(*(*list).node_mut(pivot,)).value,
+        IterMut::from_range(list, next.map(|next| (next, tail,),), len.get() - index - 1,),
+      )
+    }
+  }
+  /// Links the two runs together.
+  /// 
+  /// # Params
+  /// 
+  /// left --- The run which comes first.  
+  /// right --- The run which comes second.  
+  unsafe fn join_runs(&mut self, left: Option<Run>, right: Option<Run>,) -> Option<Run> {
+    match (left, right,) {
+      (Some(left), Some(right),) => {
+        (*self.node_mut(left.tail,)).next = Some(right.head);
+
+        Some(Run { head: left.head, tail: right.tail, len: left.len + right.len, })
+      },
+      (run, None,) | (None, run,) => run,
+    }
+  }
+  /// Restores the `prev` links and the ends of the [`VecList`] from a run covering the
+  /// whole list.
+  /// 
+  /// # Params
+  /// 
+  /// run --- The run covering the whole list.  
+  /// len --- The length of the list.  
+  unsafe fn restore_links(&mut self, run: Run, len: NonZeroUsize,) {
+    let (mut prev, mut ptr,) = (None, Some(run.head),);
+
+    (*self.node_mut(run.tail,)).next = None;
+    while let Some(node) = ptr {
+      let node = &mut *self.node_mut(node,);
+
+      node.prev = prev;
+      prev = ptr;
+      ptr = node.next;
+    }
+
+    self.ends = Some((len, run.head, run.tail,));
+  }
+  /// Relinks the [`Node`]s in the order of `slots`.
+  /// 
+  /// # Params
+  /// 
+  /// slots --- Every slot in the list in the new order.  
+  /// len --- The length of the list.  
+  pub(crate) unsafe fn relink_slots(&mut self, slots: &[usize], len: NonZeroUsize,) {
+    for (index, &slot,) in slots.iter().enumerate() {
+      let node = &mut *self.node_mut(slot,);
+
+      node.prev = index.checked_sub(1,).map(|prev| slots[prev],);
+      node.next = slots.get(index + 1,).cloned();
+    }
+
+    self.ends = Some((len, slots[0], slots[len.get() - 1],));
+  }
+  /// Detaches the run of sorted [`Node`]s starting at `start`.
+  /// 
+  /// Strictly descending runs are reversed so every run returned is ascending.
+  /// 
+  /// Returns the run and the index of the first [`Node`] after it.
+  /// 
+  /// # Params
+  /// 
+  /// start --- The index of the first [`Node`] in the run.  
+  /// cmp --- The comparison of two slots to sort by.  
+  unsafe fn take_run<F,>(&mut self, start: usize, cmp: &mut F,) -> (Run, Option<usize>,)
+    where F: FnMut(&Self, usize, usize,) -> Ordering, {
+    let second = match (*self.node(start,)).next {
+      Some(second) => second,
+      None => return (Run { head: start, tail: start, len: 1, }, None,),
+    };
+    let mut next = (*self.node(second,)).next;
+    let mut run;
+
+    //The first pair decides the direction so it is only compared once.
+    if cmp(self, second, start,) == Ordering::Less {
+      //The run is strictly descending, reverse it while it is being walked.
+      (*self.node_mut(start,)).next = None;
+      (*self.node_mut(second,)).next = Some(start);
+      run = Run { head: second, tail: start, len: 2, };
+
+      while let Some(ptr) = next {
+        if cmp(self, ptr, run.head,) != Ordering::Less { break }
+
+        next = (*self.node(ptr,)).next;
+        (*self.node_mut(ptr,)).next = Some(run.head);
+        run.head = ptr;
+        run.len += 1;
+      }
+    } else {
+      //The run is ascending.
+      run = Run { head: start, tail: second, len: 2, };
+
+      while let Some(ptr) = next {
+        if cmp(self, ptr, run.tail,) == Ordering::Less { break }
+
+        next = (*self.node(ptr,)).next;
+        run.tail = ptr;
+        run.len += 1;
+      }
+
+      (*self.node_mut(run.tail,)).next = None;
+    }
+
+    (run, next,)
+  }
+  /// Merges the runs on top of the stack until the run lengths are balanced.
+  /// 
+  /// # Params
+  /// 
+  /// runs --- The stack of runs.  
+  /// cmp --- The comparison to sort by.  
+  fn collapse_runs<F,>(&mut self, runs: &mut Vec<Run>, cmp: &mut F,)
+    where F: FnMut(&Self, usize, usize,) -> Ordering, {
+    loop {
+      let count = runs.len();
+
+      //Every run must be longer than the run after it and the two runs after it.
+      if count >= 2 && (runs[count - 2].len <= runs[count - 1].len
+        || (count >= 3 && runs[count - 3].len <= runs[count - 2].len + runs[count - 1].len)) {
+        //Merge the smaller neighbour into the middle run.
+        let at = if count >= 3 && runs[count - 3].len < runs[count - 1].len { count - 3 }
+          else { count - 2 };
+
+        self.merge_runs_at(runs, at, cmp,);
+      } else { break }
+    }
+  }
+  /// Merges the run at `at` with the run after it.
+  /// 
+  /// # Params
+  /// 
+  /// runs --- The stack of runs.  
+  /// at --- The index of the first run to merge.  
+  /// cmp --- The comparison to sort by.  
+  fn merge_runs_at<F,>(&mut self, runs: &mut Vec<Run>, at: usize, cmp: &mut F,)
+    where F: FnMut(&Self, usize, usize,) -> Ordering, {
+    let right = runs.remove(at + 1,);
+
+    runs[at] = unsafe { self.merge_runs(runs[at], right, cmp,) };
+  }
+  /// Stably merges two runs.
+  /// 
+  /// # Params
+  /// 
+  /// left --- The run which comes first.  
+  /// right --- The run which comes second.  
+  /// cmp --- The comparison to sort by.  
+  unsafe fn merge_runs<F,>(&mut self, left: Run, right: Run, cmp: &mut F,) -> Run
+    where F: FnMut(&Self, usize, usize,) -> Ordering, {
+    let (mut lhs, mut rhs,) = (Some(left.head), Some(right.head),);
+    let mut run = Run { head: left.head, tail: left.tail, len: left.len + right.len, };
+    let mut tail = None::<usize>;
+
+    //Take the lesser value until one run is empty, preferring the left run when equal.
+    while let (Some(l), Some(r),) = (lhs, rhs,) {
+      let node = if cmp(self, r, l,) == Ordering::Less {
+        rhs = (*self.node(r,)).next; r
+      } else { lhs = (*self.node(l,)).next; l };
+
+      match tail {
+        Some(tail) => (*self.node_mut(tail,)).next = Some(node),
+        None => run.head = node,
+      }
+      tail = Some(node);
+    }
+
+    //Append the rest of the unfinished run.
+    let (rest, rest_tail,) = match lhs {
+      Some(rest) => (rest, left.tail,),
+      None => (rhs.expect("`VecList::merge_runs` both runs empty"), right.tail,),
+    };
+    (*self.node_mut(tail.expect("`VecList::merge_runs` empty run"),)).next = Some(rest);
+    run.tail = rest_tail;
+
+    run
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_sort() {
+    let mut list = vec![(3, 0,), (1, 1,), (2, 2,), (1, 3,), (0, 4,),].into_iter().collect::<VecList<_,>>();
+    let slots = list.iter().map(|value,| value as *const _,).collect::<Vec<_,>>();
+
+    list.sort();
+    assert_eq!(list.to_vec(), vec![(0, 4,), (1, 1,), (1, 3,), (2, 2,), (3, 0,),], "`VecList::sort` sorted incorrectly",);
+    assert_eq!(list.iter().rev().map(|value,| value.1,).collect::<Vec<_,>>(), vec![0, 2, 3, 1, 4,], "`VecList::sort` broke the `prev` links",);
+    assert!(list.iter().all(|value,| slots.contains(&(value as *const _),),), "`VecList::sort` moved values in memory",);
+
+    list.sort_by(|lhs, rhs,| rhs.0.cmp(&lhs.0,),);
+    assert_eq!(list.to_vec(), vec![(3, 0,), (2, 2,), (1, 1,), (1, 3,), (0, 4,),], "`VecList::sort_by` was not stable",);
+    list.sort_by_key(|value,| value.1 % 2,);
+    assert_eq!(list.to_vec(), vec![(3, 0,), (2, 2,), (0, 4,), (1, 1,), (1, 3,),], "`VecList::sort_by_key` was not stable",);
+  }
+  #[test]
+  fn test_sort_runs() {
+    //Counts the comparisons made while sorting `list`.
+    fn comparisons(mut list: VecList<i32,>,) -> usize {
+      let mut calls = 0;
+
+      list.sort_by(|lhs, rhs,| { calls += 1; lhs.cmp(rhs,) },);
+      assert!(list.is_sorted(), "`VecList::sort_by` sorted incorrectly",);
+
+      calls
+    }
+
+    assert_eq!(comparisons((0..100).collect(),), 99, "`VecList::sort_by` did not detect an ascending run",);
+    assert_eq!(comparisons((0..100).rev().collect(),), 99, "`VecList::sort_by` did not detect a descending run",);
+    assert!(comparisons((50..100).chain(0..50,).collect(),) < 200, "`VecList::sort_by` did not merge the runs",);
+  }
+  #[test]
+  fn test_sort_by_cached_key() {
+    let mut list = vec![(3, 0,), (1, 1,), (2, 2,), (1, 3,), (0, 4,),].into_iter().collect::<VecList<_,>>();
+    let mut calls = 0;
+
+    list.sort_by_cached_key(|value,| { calls += 1; value.0.to_string() },);
+    assert_eq!(calls, 5, "`VecList::sort_by_cached_key` recomputed keys",);
+    assert_eq!(list.to_vec(), vec![(0, 4,), (1, 1,), (1, 3,), (2, 2,), (3, 0,),], "`VecList::sort_by_cached_key` sorted incorrectly",);
+  }
+  #[test]
+  fn test_is_sorted() {
+    let list = vec![(0, 2,), (1, 0,), (1, 1,),].into_iter().collect::<VecList<_,>>();
+
+    assert!(list.is_sorted(), "`VecList::is_sorted` rejected a sorted list",);
+    assert!(!list.is_sorted_by_key(|value,| value.1,), "`VecList::is_sorted_by_key` accepted an unsorted list",);
+    assert!(list.is_sorted_by(|lhs, rhs,| lhs.0.cmp(&rhs.0,),), "`VecList::is_sorted_by` rejected a sorted list",);
+    assert!(!vec![1, 0,].into_iter().collect::<VecList<_,>>().is_sorted(), "`VecList::is_sorted` accepted an unsorted list",);
+    assert!(VecList::<i32>::new().is_sorted(), "`VecList::is_sorted` rejected an empty list",);
+  }
+  #[test]
+  fn test_sort_values() {
+    let mut list = vec![(3, 0,), (1, 1,), (2, 2,), (1, 3,),].into_iter().collect::<VecList<_,>>();
+
+    list.pop_front();
+    list.push_front((4, 5,),);
+
+    let back = list.back_handle().expect("`VecList::back_handle` returned `None`");
+
+    list.sort_values();
+    assert_eq!(list.to_vec(), vec![(1, 1,), (1, 3,), (2, 2,), (4, 5,),], "`VecList::sort_values` sorted incorrectly",);
+    assert_eq!(list.iter().rev().map(|value,| value.1,).collect::<Vec<_,>>(), vec![5, 2, 3, 1,], "`VecList::sort_values` broke the `prev` links",);
+    assert!(!list.is_valid_handle(back,), "`VecList::sort_values` left a handle valid",);
+    assert_eq!(list.dump_layout().head, Some(0), "`VecList::sort_values` did not compact the buffer",);
+  }
+  #[test]
+  fn test_merge() {
+    let mut list = vec![(1, 1,), (1, 3,), (2, 2,), (3, 0,), (4, 5,),].into_iter().collect::<VecList<_,>>();
+    let other = vec![(0, 6,), (2, 7,), (5, 8,),].into_iter().collect::<VecList<_,>>();
+
+    list.merge_by(other, |lhs, rhs,| lhs.0.cmp(&rhs.0,),);
+    assert_eq!(list.iter().map(|value,| value.1,).collect::<Vec<_,>>(), vec![6, 1, 3, 2, 7, 0, 5, 8,], "`VecList::merge_by` merged incorrectly",);
+    assert_eq!(list.iter().rev().map(|value,| value.1,).collect::<Vec<_,>>(), vec![8, 5, 0, 7, 2, 3, 1, 6,], "`VecList::merge_by` broke the `prev` links",);
+
+    let mut list = vec![1, 3,].into_iter().collect::<VecList<_,>>();
+
+    list.merge(vec![0, 2, 4,].into_iter().collect(),);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4,], "`VecList::merge` merged incorrectly",);
+    list.merge(VecList::new(),);
+    assert_eq!(list.len(), 5, "`VecList::merge` changed the list when merging an empty list",);
+  }
+  #[test]
+  fn test_select_nth() {
+    let mut list = vec![5, 1, 4, 2, 6, 3, 0,].into_iter().collect::<VecList<i32,>>();
+
+    {
+      let (before, nth, after,) = list.select_nth_unstable_by(3, i32::cmp,);
+
+      assert_eq!(*nth, 3, "`VecList::select_nth_unstable_by` selected the wrong value",);
+      *nth = 30;
+
+      let (mut before, mut after,) = (before.map(|value,| *value,).collect::<Vec<_,>>(), after.map(|value,| *value,).collect::<Vec<_,>>(),);
+
+      before.sort(); after.sort();
+      assert_eq!((before, after,), (vec![0, 1, 2,], vec![4, 5, 6,],), "`VecList::select_nth_unstable_by` partitioned incorrectly",);
+    }
+    assert_eq!(list[3], 30, "`VecList::select_nth_unstable_by` did not place the value at `n`",);
+    assert_eq!(list.iter().rev().count(), 7, "`VecList::select_nth_unstable_by` broke the `prev` links",);
+
+    let (before, nth, after,) = list.select_nth_unstable_by(0, i32::cmp,);
+
+    assert_eq!((before.count(), *nth, after.count(),), (0, 0, 6,), "`VecList::select_nth_unstable_by` incorrect sections at the front",);
+  }
+  #[test]
+  fn test_sort_panic() {
+    use std::panic::{self, AssertUnwindSafe,};
+
+    //Panics on the `limit`th comparison.
+    fn panicking(limit: usize,) -> impl FnMut(&i32, &i32,) -> Ordering {
+      let mut calls = 0;
+
+      move |lhs, rhs,| {
+        calls += 1;
+        if calls == limit { panic!("`VecList` test comparison panic") }
+
+        lhs.cmp(rhs,)
+      }
+    }
+    fn sorted(list: &VecList<i32,>,) -> Vec<i32> {
+      let mut values = list.to_vec();
+
+      values.sort(); values
+    }
+
+    let mut list = vec![5, 1, 4, 2, 3, 0,].into_iter().collect::<VecList<i32,>>();
+
+    list.pop_front();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| list.sort_by(panicking(4,),),),).is_err(), "`VecList::sort_by` swallowed the panic",);
+    assert_eq!(sorted(&list,), vec![0, 1, 2, 3, 4,], "`VecList::sort_by` lost values on panic",);
+    assert_eq!(list.iter().rev().count(), 5, "`VecList::sort_by` broke the `prev` links on panic",);
+
+    let other = vec![6, 7,].into_iter().collect::<VecList<i32,>>();
+
+    list.sort();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| list.merge_by(other, panicking(2,),),),).is_err(), "`VecList::merge_by` swallowed the panic",);
+    assert_eq!(sorted(&list,), vec![0, 1, 2, 3, 4, 6, 7,], "`VecList::merge_by` lost values on panic",);
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| { list.select_nth_unstable_by(2, panicking(3,),); },),).is_err(), "`VecList::select_nth_unstable_by` swallowed the panic",);
+    assert_eq!(sorted(&list,), vec![0, 1, 2, 3, 4, 6, 7,], "`VecList::select_nth_unstable_by` lost values on panic",);
+    list.push_back(8,);
+    assert_eq!(list.len(), 8, "`VecList` inconsistent after a panic",);
+  }
+}
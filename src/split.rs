@@ -0,0 +1,123 @@
+
+use {VecList, Alloc,};
+use std::mem;
+
+impl<T, A: Alloc + Clone,> VecList<T, A,> {
+  /// Splits the [`VecList`] in two at `at`.
+  /// 
+  /// Returns a new [`VecList`] containing the values from `at` onwards with its
+  /// [`Node`]s laid out contiguously; the values before `at` stay where they are unless
+  /// the compaction policy compacts the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// at --- The index of the first value to move into the new [`VecList`].  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `at > self.len()`.
+  pub fn split_off(&mut self, at: usize,) -> Self {
+    assert!(at <= self.len(), "`VecList::split_off` index out of range",);
+
+    let mut other = self.empty_like(self.len() - at,);
+
+    other.extend(self.drain(at..),);
+
+    other
+  }
+  /// Splits the [`VecList`] into two [`VecList`]s at `at`.
+  /// 
+  /// Only the values of the smaller half are moved into a new buffer; the larger half
+  /// keeps the buffer of the [`VecList`].
+  /// 
+  /// # Params
+  /// 
+  /// at --- The index of the first value in the second [`VecList`].  
+  /// 
+  /// # Panics
+  /// 
+  /// * If `at > self.len()`.
+  pub fn split_at(mut self, at: usize,) -> (Self, Self,) {
+    assert!(at <= self.len(), "`VecList::split_at` index out of range",);
+
+    //Move the back half.
+    if at >= self.len() - at {
+      let back = self.split_off(at,);
+
+      return (self, back,)
+    }
+
+    //Move the front half.
+    let mut front = self.empty_like(at,);
+
+    front.extend(self.drain(..at),);
+
+    (front, self,)
+  }
+  /// Moves all the values of `other` onto the back of the [`VecList`] leaving `other`
+  /// empty.
+  /// 
+  /// Space for the values is reserved once and the values are moved in list order
+  /// without walking `other` more than once. If the [`VecList`] is empty the buffers are
+  /// swapped instead.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The [`VecList`] to move the values out of.  
+  pub fn append(&mut self, other: &mut Self,) {
+    if other.ends.is_none() { return }
+
+    //Take the buffer of `other` but keep the policies.
+    if self.ends.is_none() {
+      mem::swap(self, other,);
+      mem::swap(&mut self.growth, &mut other.growth,);
+      mem::swap(&mut self.compaction, &mut other.compaction,);
+
+      return
+    }
+
+    if let Some((start, end, count,)) = self.adopt_nodes(other,) {
+      unsafe { self.link_segment_before(None, start, end, count,) }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split() {
+    let mut list = (0..5).collect::<VecList<i32,>>();
+    let other = list.split_off(2,);
+
+    assert_eq!(list, [0, 1,], "`VecList::split_off` kept the wrong values",);
+    assert_eq!(other, [2, 3, 4,], "`VecList::split_off` moved the wrong values",);
+    assert_eq!(other.capacity(), 3, "`VecList::split_off` incorrect capacity",);
+  }
+  #[test]
+  fn test_append() {
+    let mut list = (0..2).collect::<VecList<i32,>>();
+    let mut other = (2..5).collect::<VecList<i32,>>();
+
+    list.append(&mut other,);
+    assert_eq!(list, [0, 1, 2, 3, 4,], "`VecList::append` incorrect values",);
+    assert_eq!(list.iter().rev().cloned().collect::<Vec<_,>>(), vec![4, 3, 2, 1, 0,], "`VecList::append` broke the `prev` links",);
+    assert_eq!(other.len(), 0, "`VecList::append` did not empty `other`",);
+    other.push_back(5,);
+    assert_eq!(other.capacity(), 3, "`VecList::append` did not reuse the empty `Node`s",);
+  }
+  #[test]
+  fn test_split_at() {
+    let list = (0..5).collect::<VecList<i32,>>();
+    let (front, back,) = list.split_at(1,);
+
+    assert_eq!(front, [0,], "`VecList::split_at` incorrect front",);
+    assert_eq!(back, [1, 2, 3, 4,], "`VecList::split_at` incorrect back",);
+    assert_eq!(front.capacity(), 1, "`VecList::split_at` moved the larger half",);
+
+    let (front, back,) = back.split_at(3,);
+
+    assert_eq!((front.to_vec(), back.to_vec(),), (vec![1, 2, 3,], vec![4,],), "`VecList::split_at` incorrect halves",);
+  }
+}